@@ -0,0 +1,18 @@
+//! Measures the time taken to mint blank node identifiers.
+//!
+//! Run with `cargo bench --bench blank_id`.
+use rdf_types::generator;
+use std::time::Instant;
+
+const COUNT: usize = 1_000_000;
+
+fn main() {
+	let mut generator = generator::Blank::new_with_prefix("b".to_owned());
+
+	let start = Instant::now();
+	for _ in 0..COUNT {
+		std::hint::black_box(generator.next_blank_id());
+	}
+
+	println!("minted {COUNT} blank ids in {:?}", start.elapsed())
+}
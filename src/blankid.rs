@@ -1,5 +1,6 @@
 use std::borrow::{Borrow, ToOwned};
 use std::fmt;
+use std::hash::{Hash, Hasher};
 use std::ops::Deref;
 use std::str::FromStr;
 
@@ -7,7 +8,8 @@ use std::str::FromStr;
 ///
 /// This error is raised by the [`BlankId::new`] and [`BlankIdBuf::new`] functions
 /// when the input string is not a valid blank node identifier.
-#[derive(Debug)]
+#[derive(Debug, thiserror::Error)]
+#[error("invalid blank node identifier `{0}`")]
 pub struct InvalidBlankId<T>(pub T);
 
 /// Blank node identifier.
@@ -90,7 +92,7 @@ impl ToOwned for BlankId {
 
 	#[inline(always)]
 	fn to_owned(&self) -> BlankIdBuf {
-		unsafe { BlankIdBuf::new_unchecked(self.as_str().to_owned()) }
+		BlankIdBuf(Repr::new(self.as_str()))
 	}
 }
 
@@ -126,14 +128,92 @@ impl PartialEq<str> for BlankId {
 /// [158s] PN_CHARS_U       ::= PN_CHARS_BASE | '_' | ':'
 /// [160s] PN_CHARS         ::= PN_CHARS_U | '-' | [0-9] | #x00B7 | [#x0300-#x036F] | [#x203F-#x2040]
 /// ```
-#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize), serde(transparent))]
-pub struct BlankIdBuf(String);
+///
+/// Identifiers of up to 22 bytes, such as the ones minted by
+/// [`generator::Blank`](crate::generator::Blank), are stored inline without
+/// any heap allocation.
+#[derive(Clone)]
+pub struct BlankIdBuf(Repr);
+
+/// Maximum length, in bytes, of a blank node identifier stored inline.
+const INLINE_CAPACITY: usize = 22;
+
+/// Blank node identifier buffer representation.
+#[derive(Clone)]
+enum Repr {
+	/// Short identifier, stored inline.
+	Inline {
+		len: u8,
+		bytes: [u8; INLINE_CAPACITY],
+	},
+
+	/// Long identifier, stored on the heap.
+	Heap(Box<str>),
+}
+
+impl Repr {
+	fn new(s: &str) -> Self {
+		if s.len() <= INLINE_CAPACITY {
+			let mut bytes = [0; INLINE_CAPACITY];
+			bytes[..s.len()].copy_from_slice(s.as_bytes());
+			Self::Inline {
+				len: s.len() as u8,
+				bytes,
+			}
+		} else {
+			Self::Heap(s.into())
+		}
+	}
+
+	fn from_string(s: String) -> Self {
+		if s.len() <= INLINE_CAPACITY {
+			Self::new(&s)
+		} else {
+			Self::Heap(s.into_boxed_str())
+		}
+	}
+
+	fn as_str(&self) -> &str {
+		match self {
+			// SAFETY: inline bytes are always copied from a `str`.
+			Self::Inline { len, bytes } => unsafe {
+				std::str::from_utf8_unchecked(&bytes[..*len as usize])
+			},
+			Self::Heap(s) => s,
+		}
+	}
+}
+
+/// Writer filling an inline blank node identifier buffer, failing if the
+/// written string does not fit.
+#[derive(Default)]
+struct InlineWriter {
+	len: usize,
+	bytes: [u8; INLINE_CAPACITY],
+}
+
+impl fmt::Write for InlineWriter {
+	fn write_str(&mut self, s: &str) -> fmt::Result {
+		let end = self.len + s.len();
+		if end <= INLINE_CAPACITY {
+			self.bytes[self.len..end].copy_from_slice(s.as_bytes());
+			self.len = end;
+			Ok(())
+		} else {
+			Err(fmt::Error)
+		}
+	}
+}
 
 impl BlankIdBuf {
 	/// Parses a blank node identifier.
+	///
+	/// The input must match the `BLANK_NODE_LABEL` production (see
+	/// [`BlankId`]). Use [`Self::new_unchecked`] to skip this check when the
+	/// input is known to be valid.
 	#[inline(always)]
-	pub fn new(s: String) -> Result<Self, InvalidBlankId<String>> {
+	pub fn new(s: impl Into<String>) -> Result<Self, InvalidBlankId<String>> {
+		let s = s.into();
 		if check(s.chars()) {
 			Ok(unsafe { Self::new_unchecked(s) })
 		} else {
@@ -141,6 +221,15 @@ impl BlankIdBuf {
 		}
 	}
 
+	/// Parses a single N-Triples blank node label.
+	///
+	/// Blank node labels have no escape sequences, so this is equivalent to
+	/// [`Self::new`]. It is provided for symmetry with
+	/// [`Literal::from_ntriples`](crate::Literal::from_ntriples).
+	pub fn from_ntriples(s: &str) -> Result<Self, InvalidBlankId<String>> {
+		Self::new(s.to_owned())
+	}
+
 	/// Creates a new blank node identifier from `s` without checking it.
 	///
 	/// # Safety
@@ -148,31 +237,51 @@ impl BlankIdBuf {
 	/// The input string `s` must be a valid blank node identifier.
 	#[inline(always)]
 	pub unsafe fn new_unchecked(s: String) -> Self {
-		std::mem::transmute(s)
+		Self(Repr::from_string(s))
+	}
+
+	/// Creates a new blank node identifier from the given format arguments
+	/// without checking it.
+	///
+	/// No heap allocation is performed if the formatted identifier can be
+	/// stored inline.
+	///
+	/// # Safety
+	///
+	/// The formatted string must be a valid blank node identifier.
+	pub(crate) unsafe fn from_fmt_unchecked(args: fmt::Arguments) -> Self {
+		let mut writer = InlineWriter::default();
+		match fmt::Write::write_fmt(&mut writer, args) {
+			Ok(()) => Self(Repr::Inline {
+				len: writer.len as u8,
+				bytes: writer.bytes,
+			}),
+			Err(_) => Self::new_unchecked(args.to_string()),
+		}
 	}
 
 	/// Creates a blank node identifier using the given `u8` as suffix.
 	#[inline(always)]
 	pub fn from_u8(i: u8) -> Self {
-		unsafe { Self::new_unchecked(format!("_:{i}")) }
+		unsafe { Self::from_fmt_unchecked(format_args!("_:{i}")) }
 	}
 
 	/// Creates a blank node identifier using the given `u16` as suffix.
 	#[inline(always)]
 	pub fn from_u16(i: u16) -> Self {
-		unsafe { Self::new_unchecked(format!("_:{i}")) }
+		unsafe { Self::from_fmt_unchecked(format_args!("_:{i}")) }
 	}
 
 	/// Creates a blank node identifier using the given `u32` as suffix.
 	#[inline(always)]
 	pub fn from_u32(i: u32) -> Self {
-		unsafe { Self::new_unchecked(format!("_:{i}")) }
+		unsafe { Self::from_fmt_unchecked(format_args!("_:{i}")) }
 	}
 
 	/// Creates a blank node identifier using the given `u64` as suffix.
 	#[inline(always)]
 	pub fn from_u64(i: u64) -> Self {
-		unsafe { Self::new_unchecked(format!("_:{i}")) }
+		unsafe { Self::from_fmt_unchecked(format_args!("_:{i}")) }
 	}
 
 	/// Creates a blank node identifier using the given suffix.
@@ -184,7 +293,7 @@ impl BlankIdBuf {
 	/// Returns a reference to this blank id as a `BlankId`.
 	#[inline(always)]
 	pub fn as_blank_id_ref(&self) -> &BlankId {
-		unsafe { BlankId::new_unchecked(&self.0) }
+		unsafe { BlankId::new_unchecked(self.0.as_str()) }
 	}
 }
 
@@ -236,7 +345,7 @@ impl Borrow<str> for BlankIdBuf {
 impl AsRef<[u8]> for BlankIdBuf {
 	#[inline(always)]
 	fn as_ref(&self) -> &[u8] {
-		self.0.as_bytes()
+		self.0.as_str().as_bytes()
 	}
 }
 
@@ -264,14 +373,40 @@ impl<'a> From<&'a BlankIdBuf> for &'a BlankId {
 impl fmt::Display for BlankIdBuf {
 	#[inline(always)]
 	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-		self.0.fmt(f)
+		self.0.as_str().fmt(f)
 	}
 }
 
 impl fmt::Debug for BlankIdBuf {
 	#[inline(always)]
 	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-		self.0.fmt(f)
+		self.0.as_str().fmt(f)
+	}
+}
+
+impl PartialEq for BlankIdBuf {
+	fn eq(&self, other: &Self) -> bool {
+		self.0.as_str() == other.0.as_str()
+	}
+}
+
+impl Eq for BlankIdBuf {}
+
+impl PartialOrd for BlankIdBuf {
+	fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+		Some(self.cmp(other))
+	}
+}
+
+impl Ord for BlankIdBuf {
+	fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+		self.0.as_str().cmp(other.0.as_str())
+	}
+}
+
+impl Hash for BlankIdBuf {
+	fn hash<H: Hasher>(&self, state: &mut H) {
+		self.0.as_str().hash(state)
 	}
 }
 
@@ -304,13 +439,16 @@ fn check<C: Iterator<Item = char>>(mut chars: C) -> bool {
 		Some('_') => match chars.next() {
 			Some(':') => match chars.next() {
 				Some(c) if c.is_ascii_digit() || is_pn_char_u(c) => {
+					let mut last = c;
 					for c in chars {
-						if !is_pn_char(c) {
+						if !is_pn_char(c) && c != '.' {
 							return false;
 						}
+
+						last = c
 					}
 
-					true
+					last != '.'
 				}
 				_ => false,
 			},
@@ -320,19 +458,29 @@ fn check<C: Iterator<Item = char>>(mut chars: C) -> bool {
 	}
 }
 
-fn is_pn_char_base(c: char) -> bool {
+pub(crate) fn is_pn_char_base(c: char) -> bool {
 	matches!(c, 'A'..='Z' | 'a'..='z' | '\u{00c0}'..='\u{00d6}' | '\u{00d8}'..='\u{00f6}' | '\u{00f8}'..='\u{02ff}' | '\u{0370}'..='\u{037d}' | '\u{037f}'..='\u{1fff}' | '\u{200c}'..='\u{200d}' | '\u{2070}'..='\u{218f}' | '\u{2c00}'..='\u{2fef}' | '\u{3001}'..='\u{d7ff}' | '\u{f900}'..='\u{fdcf}' | '\u{fdf0}'..='\u{fffd}' | '\u{10000}'..='\u{effff}')
 }
 
-fn is_pn_char_u(c: char) -> bool {
+pub(crate) fn is_pn_char_u(c: char) -> bool {
 	is_pn_char_base(c) || matches!(c, '_' | ':')
 }
 
-fn is_pn_char(c: char) -> bool {
+pub(crate) fn is_pn_char(c: char) -> bool {
 	is_pn_char_u(c)
 		|| matches!(c, '-' | '0'..='9' | '\u{00b7}' | '\u{0300}'..='\u{036f}' | '\u{203f}'..='\u{2040}')
 }
 
+#[cfg(feature = "serde")]
+impl serde::Serialize for BlankIdBuf {
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		serializer.serialize_str(self.0.as_str())
+	}
+}
+
 #[cfg(feature = "serde")]
 impl<'de> serde::Deserialize<'de> for BlankIdBuf {
 	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
@@ -375,3 +523,52 @@ impl<'de> serde::Deserialize<'de> for BlankIdBuf {
 		deserializer.deserialize_string(Visitor)
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn inline_and_heap() {
+		let short = BlankIdBuf::from_u64(u64::MAX);
+		assert!(matches!(short.0, Repr::Inline { .. }));
+		assert_eq!(short.as_str(), "_:18446744073709551615");
+
+		let long = BlankIdBuf::from_suffix("a_rather_long_blank_node_identifier").unwrap();
+		assert!(matches!(long.0, Repr::Heap(_)));
+		assert_eq!(long.suffix(), "a_rather_long_blank_node_identifier");
+
+		assert!(short < long);
+		assert_eq!(long.as_blank_id_ref().to_owned(), long)
+	}
+
+	#[test]
+	fn validation() {
+		assert!(BlankIdBuf::new("_:b0").is_ok());
+		assert!(BlankIdBuf::new("_:0.a-b").is_ok());
+		assert!(BlankId::new("_:a.").is_err());
+		assert!(BlankId::new("_:.a").is_err());
+		assert!(BlankId::new("_:-a").is_err());
+		assert!(BlankId::new("b0").is_err());
+
+		let error = BlankIdBuf::new("_:a b").unwrap_err();
+		assert_eq!(error.to_string(), "invalid blank node identifier `_:a b`")
+	}
+
+	#[test]
+	fn borrowed_lookup() {
+		let mut map = std::collections::HashMap::new();
+		map.insert(BlankIdBuf::from_u64(0), 0);
+		map.insert(
+			BlankIdBuf::from_suffix("a_rather_long_blank_node_identifier").unwrap(),
+			1,
+		);
+
+		assert_eq!(map.get(BlankId::new("_:0").unwrap()), Some(&0));
+		assert_eq!(
+			map.get(BlankId::new("_:a_rather_long_blank_node_identifier").unwrap()),
+			Some(&1)
+		);
+		assert_eq!(map.get(BlankId::new("_:1").unwrap()), None)
+	}
+}
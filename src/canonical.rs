@@ -0,0 +1,92 @@
+//! Canonical serialization of RDF datasets.
+use crate::{canonicalize::canonical_labels, BlankIdBuf, Id, LexicalQuad, Quad, Term};
+
+/// Serializes the given dataset into a canonical N-Quads document.
+///
+/// Blank node identifiers are relabeled with their
+/// [canonical labels](canonical_labels) (`_:c14n0`, `_:c14n1`, etc.) so that
+/// isomorphic datasets produce the same document, duplicate statements are
+/// removed and the remaining statements are sorted in code point order.
+///
+/// The output is deterministic and idempotent: canonicalizing the statements
+/// of a canonical document yields the same document.
+pub fn to_canonical_nquads(quads: &[LexicalQuad]) -> String {
+	let labels = canonical_labels(quads);
+	let mut lines: Vec<_> = quads
+		.iter()
+		.map(|quad| statement(quad, |b| labels[b].clone()))
+		.collect();
+	lines.sort_unstable();
+	lines.dedup();
+	lines.concat()
+}
+
+/// Serializes the given quad as an N-Quads statement, relabeling its blank
+/// nodes.
+fn statement(quad: &LexicalQuad, mut f: impl FnMut(&BlankIdBuf) -> BlankIdBuf) -> String {
+	let mut id = |id: &Id| match id {
+		Id::Iri(iri) => Id::Iri(iri.clone()),
+		Id::Blank(b) => Id::Blank(f(b)),
+	};
+
+	let quad = Quad(
+		id(&quad.0),
+		quad.1.clone(),
+		match &quad.2 {
+			Term::Id(i) => Term::Id(id(i)),
+			Term::Literal(l) => Term::Literal(l.clone()),
+		},
+		quad.3.as_ref().map(&mut id),
+	);
+
+	format!("{quad} .\n")
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn parse(document: &str) -> Vec<LexicalQuad> {
+		document
+			.lines()
+			.map(|line| line.parse::<LexicalQuad>().unwrap())
+			.collect()
+	}
+
+	#[test]
+	fn isomorphic_datasets() {
+		let a = parse(
+			"_:x <http://example.org/#p> _:y <http://example.org/#g> .\n\
+			_:y <http://example.org/#q> \"value\" .\n\
+			_:y <http://example.org/#p> _:z .",
+		);
+		let b = parse(
+			"_:b2 <http://example.org/#p> _:b0 .\n\
+			_:b1 <http://example.org/#p> _:b2 <http://example.org/#g> .\n\
+			_:b2 <http://example.org/#q> \"value\" .",
+		);
+
+		let canonical = to_canonical_nquads(&a);
+		assert_eq!(canonical, to_canonical_nquads(&b));
+		assert_eq!(
+			canonical,
+			"_:c14n0 <http://example.org/#p> _:c14n2 <http://example.org/#g> .\n\
+			_:c14n2 <http://example.org/#p> _:c14n1 .\n\
+			_:c14n2 <http://example.org/#q> \"value\" .\n"
+		)
+	}
+
+	#[test]
+	fn idempotence() {
+		let quads = parse(
+			"_:a <http://example.org/#p> _:b .\n\
+			_:b <http://example.org/#p> _:a .\n\
+			_:c <http://example.org/#p> _:d .\n\
+			_:d <http://example.org/#p> _:c .\n\
+			_:a <http://example.org/#p> _:a .",
+		);
+
+		let canonical = to_canonical_nquads(&quads);
+		assert_eq!(canonical, to_canonical_nquads(&parse(&canonical)))
+	}
+}
@@ -0,0 +1,402 @@
+//! Canonical blank node labeling, following the [RDFC-1.0][1] algorithm.
+//!
+//! [1]: <https://www.w3.org/TR/rdf-canon/>
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fmt::Write;
+use std::ptr;
+
+use indexmap::IndexMap;
+use sha2::{Digest, Sha256};
+
+use crate::{BlankIdBuf, Id, LexicalQuad, RdfDisplay};
+
+/// Quads mentioning each blank node.
+type Mentions<'a> = HashMap<&'a BlankIdBuf, Vec<&'a LexicalQuad>>;
+
+/// Computes canonical labels for the blank nodes of the given dataset.
+///
+/// Maps each blank node identifier of the dataset to its canonical label
+/// (`_:c14n0`, `_:c14n1`, etc.) as computed by the [RDFC-1.0][1] algorithm.
+/// Labels are computed from the structure of the dataset only: isomorphic
+/// datasets get the same labels for corresponding blank nodes. Duplicate
+/// statements are ignored.
+///
+/// Blank nodes are first labeled by the hash of the statements mentioning
+/// them (first degree hash). Blank nodes sharing the same first degree hash
+/// are then distinguished by exploring their neighborhood (N-degree hash),
+/// which may be expensive for highly symmetric datasets.
+///
+/// [1]: <https://www.w3.org/TR/rdf-canon/#canon-algorithm>
+pub fn canonical_labels(quads: &[LexicalQuad]) -> HashMap<BlankIdBuf, BlankIdBuf> {
+	let dataset: HashSet<&LexicalQuad> = quads.iter().collect();
+
+	let mut mentions = Mentions::new();
+	for quad in dataset {
+		for b in blank_ids(quad) {
+			let list = mentions.entry(b).or_default();
+			if !list.last().is_some_and(|q| ptr::eq(*q, quad)) {
+				list.push(quad)
+			}
+		}
+	}
+
+	let mut by_hash: BTreeMap<String, Vec<&BlankIdBuf>> = BTreeMap::new();
+	for b in mentions.keys() {
+		by_hash
+			.entry(first_degree_hash(&mentions, b))
+			.or_default()
+			.push(*b)
+	}
+
+	let mut canonical = IdentifierIssuer::new("c14n");
+	by_hash.retain(|_, list| match list.as_slice() {
+		[b] => {
+			canonical.issue(b);
+			false
+		}
+		_ => true,
+	});
+
+	for list in by_hash.into_values() {
+		let mut results = Vec::new();
+		for b in list {
+			if !canonical.contains(b) {
+				let mut issuer = IdentifierIssuer::new("b");
+				issuer.issue(b);
+				results.push(n_degree_hash(&mentions, &canonical, b, issuer))
+			}
+		}
+
+		results.sort_by(|a, b| a.0.cmp(&b.0));
+		for (_, issuer) in results {
+			for b in issuer.issued.keys() {
+				canonical.issue(b);
+			}
+		}
+	}
+
+	canonical
+		.issued
+		.into_iter()
+		.map(|(b, label)| (b.clone(), label))
+		.collect()
+}
+
+/// Checks if the two given datasets are isomorphic, that is equal up to blank
+/// node relabeling.
+///
+/// Both datasets are relabeled with their [`canonical_labels`] before being
+/// compared. Duplicate statements are ignored.
+pub fn isomorphic(a: &[LexicalQuad], b: &[LexicalQuad]) -> bool {
+	crate::canonical::to_canonical_nquads(a) == crate::canonical::to_canonical_nquads(b)
+}
+
+/// Issues sequential blank node identifiers with a given prefix, remembering
+/// the order in which they were issued.
+#[derive(Clone)]
+struct IdentifierIssuer<'a> {
+	prefix: &'static str,
+	issued: IndexMap<&'a BlankIdBuf, BlankIdBuf>,
+}
+
+impl<'a> IdentifierIssuer<'a> {
+	fn new(prefix: &'static str) -> Self {
+		Self {
+			prefix,
+			issued: IndexMap::new(),
+		}
+	}
+
+	fn contains(&self, b: &BlankIdBuf) -> bool {
+		self.issued.contains_key(b)
+	}
+
+	fn get(&self, b: &BlankIdBuf) -> Option<&BlankIdBuf> {
+		self.issued.get(b)
+	}
+
+	/// Returns the identifier issued for the given blank node, issuing a new
+	/// one if necessary.
+	fn issue(&mut self, b: &'a BlankIdBuf) -> &BlankIdBuf {
+		let n = self.issued.len();
+		let prefix = self.prefix;
+		self.issued
+			.entry(b)
+			.or_insert_with(|| BlankIdBuf::from_suffix(&format!("{prefix}{n}")).unwrap())
+	}
+}
+
+/// Computes the first degree hash of the given blank node.
+///
+/// See <https://www.w3.org/TR/rdf-canon/#hash-1d-quads>.
+fn first_degree_hash(mentions: &Mentions, b: &BlankIdBuf) -> String {
+	let mut inputs: Vec<_> = mentions[b]
+		.iter()
+		.map(|quad| quad.first_degree_hash_input(b))
+		.collect();
+	inputs.sort_unstable();
+	hash(&inputs.concat())
+}
+
+/// Computes the hash of a blank node related to another blank node through
+/// the given quad, where it appears at the given position (`s`, `o` or
+/// `g`).
+///
+/// See <https://www.w3.org/TR/rdf-canon/#hash-related-blank-node>.
+fn related_hash(
+	mentions: &Mentions,
+	canonical: &IdentifierIssuer,
+	issuer: &IdentifierIssuer,
+	related: &BlankIdBuf,
+	quad: &LexicalQuad,
+	position: char,
+) -> String {
+	let mut input = position.to_string();
+	if position != 'g' {
+		write!(input, "{}", quad.1.rdf_display()).unwrap();
+	}
+
+	match canonical.get(related).or_else(|| issuer.get(related)) {
+		Some(id) => input.push_str(id.as_str()),
+		None => input.push_str(&first_degree_hash(mentions, related)),
+	}
+
+	hash(&input)
+}
+
+/// Computes the N-degree hash of the given blank node, returning the hash
+/// and the updated temporary identifier issuer.
+///
+/// See <https://www.w3.org/TR/rdf-canon/#hash-nd-quads>.
+fn n_degree_hash<'a>(
+	mentions: &Mentions<'a>,
+	canonical: &IdentifierIssuer,
+	b: &BlankIdBuf,
+	mut issuer: IdentifierIssuer<'a>,
+) -> (String, IdentifierIssuer<'a>) {
+	let mut related: BTreeMap<String, Vec<&'a BlankIdBuf>> = BTreeMap::new();
+	for quad in &mentions[b] {
+		let components = [
+			(quad.0.as_blank(), 's'),
+			(quad.2.as_id().and_then(Id::as_blank), 'o'),
+			(quad.3.as_ref().and_then(Id::as_blank), 'g'),
+		];
+
+		for (component, position) in components {
+			if let Some(r) = component.filter(|r| *r != b) {
+				let hash = related_hash(mentions, canonical, &issuer, r, quad, position);
+				related.entry(hash).or_default().push(r)
+			}
+		}
+	}
+
+	let mut data = String::new();
+	for (related_hash, list) in related {
+		data.push_str(&related_hash);
+
+		let mut chosen: Option<(String, IdentifierIssuer)> = None;
+		let mut permutation: Vec<usize> = (0..list.len()).collect();
+		loop {
+			if let Some(candidate) =
+				permutation_path(mentions, canonical, &issuer, &list, &permutation, &chosen)
+			{
+				if !chosen
+					.as_ref()
+					.is_some_and(|(path, _)| candidate.0 >= *path)
+				{
+					chosen = Some(candidate)
+				}
+			}
+
+			if !next_permutation(&mut permutation) {
+				break;
+			}
+		}
+
+		let (path, chosen_issuer) = chosen.unwrap();
+		data.push_str(&path);
+		issuer = chosen_issuer
+	}
+
+	(hash(&data), issuer)
+}
+
+/// Computes the path of the given permutation of related blank nodes.
+///
+/// Returns `None` if the path cannot be smaller than the currently chosen
+/// path.
+fn permutation_path<'a>(
+	mentions: &Mentions<'a>,
+	canonical: &IdentifierIssuer,
+	issuer: &IdentifierIssuer<'a>,
+	list: &[&'a BlankIdBuf],
+	permutation: &[usize],
+	chosen: &Option<(String, IdentifierIssuer)>,
+) -> Option<(String, IdentifierIssuer<'a>)> {
+	let exceeds = |path: &String| {
+		chosen
+			.as_ref()
+			.is_some_and(|(chosen, _)| path.len() >= chosen.len() && path > chosen)
+	};
+
+	let mut issuer = issuer.clone();
+	let mut path = String::new();
+	let mut recursion = Vec::new();
+	for &i in permutation {
+		let r = list[i];
+		match canonical.get(r) {
+			Some(id) => path.push_str(id.as_str()),
+			None => {
+				if !issuer.contains(r) {
+					recursion.push(r)
+				}
+
+				path.push_str(issuer.issue(r).as_str())
+			}
+		}
+
+		if exceeds(&path) {
+			return None;
+		}
+	}
+
+	for r in recursion {
+		let (hash, result) = n_degree_hash(mentions, canonical, r, issuer.clone());
+		path.push_str(issuer.issue(r).as_str());
+		write!(path, "<{hash}>").unwrap();
+		issuer = result;
+
+		if exceeds(&path) {
+			return None;
+		}
+	}
+
+	Some((path, issuer))
+}
+
+/// Rearranges the given indexes into the next permutation in lexicographic
+/// order.
+///
+/// Returns `false` once every permutation has been visited.
+fn next_permutation(permutation: &mut [usize]) -> bool {
+	let Some(i) = permutation.windows(2).rposition(|w| w[0] < w[1]) else {
+		return false;
+	};
+
+	let j = permutation
+		.iter()
+		.rposition(|x| *x > permutation[i])
+		.unwrap();
+	permutation.swap(i, j);
+	permutation[i + 1..].reverse();
+	true
+}
+
+fn blank_ids(quad: &LexicalQuad) -> impl Iterator<Item = &BlankIdBuf> {
+	quad.0
+		.as_blank()
+		.into_iter()
+		.chain(quad.2.as_id().and_then(Id::as_blank))
+		.chain(quad.3.as_ref().and_then(Id::as_blank))
+}
+
+/// Lowercase hexadecimal SHA-256 hash of the given input.
+fn hash(input: &str) -> String {
+	Sha256::digest(input.as_bytes())
+		.iter()
+		.map(|b| format!("{b:02x}"))
+		.collect()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn parse(document: &str) -> Vec<LexicalQuad> {
+		document
+			.lines()
+			.map(|line| line.parse::<LexicalQuad>().unwrap())
+			.collect()
+	}
+
+	fn blank(s: &str) -> BlankIdBuf {
+		BlankIdBuf::new(s.to_owned()).unwrap()
+	}
+
+	#[test]
+	fn first_degree() {
+		let quads = parse(
+			"_:x <http://example.org/#p> _:y .\n\
+			_:y <http://example.org/#q> \"value\" .",
+		);
+
+		let labels = canonical_labels(&quads);
+		let x = hash("_:a <http://example.org/#p> _:z .\n");
+		let y =
+			hash("_:a <http://example.org/#q> \"value\" .\n_:z <http://example.org/#p> _:a .\n");
+		let (first, second) = if x < y {
+			("_:x", "_:y")
+		} else {
+			("_:y", "_:x")
+		};
+		assert_eq!(labels[&blank(first)], blank("_:c14n0"));
+		assert_eq!(labels[&blank(second)], blank("_:c14n1"));
+	}
+
+	#[test]
+	fn labels() {
+		let a = parse(
+			"_:x <http://example.org/#p> _:y .\n\
+			_:y <http://example.org/#q> \"value\" .",
+		);
+		let b = parse(
+			"_:b1 <http://example.org/#q> \"value\" .\n\
+			_:b0 <http://example.org/#p> _:b1 .",
+		);
+
+		let labels_a = canonical_labels(&a);
+		let labels_b = canonical_labels(&b);
+		assert_eq!(labels_a[&blank("_:x")], labels_b[&blank("_:b0")]);
+		assert_eq!(labels_a[&blank("_:y")], labels_b[&blank("_:b1")]);
+		assert!(isomorphic(&a, &b));
+
+		let c = parse("_:b0 <http://example.org/#p> _:b0 .");
+		assert!(!isomorphic(&a, &c))
+	}
+
+	#[test]
+	fn symmetric() {
+		let a = parse(
+			"_:a <http://example.org/#p> _:b .\n\
+			_:b <http://example.org/#p> _:c .\n\
+			_:c <http://example.org/#p> _:a .\n\
+			_:d <http://example.org/#p> _:e .\n\
+			_:e <http://example.org/#p> _:d .",
+		);
+		let b = parse(
+			"_:y <http://example.org/#p> _:x .\n\
+			_:z <http://example.org/#p> _:y .\n\
+			_:x <http://example.org/#p> _:z .\n\
+			_:v <http://example.org/#p> _:w .\n\
+			_:w <http://example.org/#p> _:v .",
+		);
+		let c = parse(
+			"_:a <http://example.org/#p> _:b .\n\
+			_:b <http://example.org/#p> _:a .\n\
+			_:c <http://example.org/#p> _:d .\n\
+			_:d <http://example.org/#p> _:e .\n\
+			_:e <http://example.org/#p> _:c .\n\
+			_:c <http://example.org/#p> _:c .",
+		);
+
+		let labels = canonical_labels(&a);
+		let mut issued: Vec<_> = labels.values().map(|b| b.as_str()).collect();
+		issued.sort_unstable();
+		assert_eq!(
+			issued,
+			["_:c14n0", "_:c14n1", "_:c14n2", "_:c14n3", "_:c14n4"]
+		);
+		assert!(isomorphic(&a, &b));
+		assert!(!isomorphic(&a, &c))
+	}
+}
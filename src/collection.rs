@@ -0,0 +1,125 @@
+//! RDF collections (`rdf:List`).
+//!
+//! An RDF collection is a linked list of nodes, where each node gives an
+//! item of the list with the `rdf:first` property and the rest of the list
+//! with the `rdf:rest` property. The empty list is `rdf:nil`.
+use std::collections::HashSet;
+
+use iref::Iri;
+
+use crate::{
+	generator::Generator, Id, LexicalTriple, Object, Subject, Term, Triple, RDF_FIRST, RDF_NIL,
+	RDF_REST,
+};
+
+/// Encodes the given items into an RDF collection.
+///
+/// A fresh node is minted by `generator` for each item of the list. Returns
+/// the head of the list, which is `rdf:nil` if there are no items, along with
+/// the `rdf:first`/`rdf:rest` triples linking the nodes.
+pub fn encode<G: Generator>(
+	items: impl IntoIterator<Item = Object>,
+	generator: &mut G,
+) -> (Subject, Vec<LexicalTriple>) {
+	let nodes: Vec<_> = items
+		.into_iter()
+		.map(|item| (generator.next(&mut ()), item))
+		.collect();
+
+	let mut triples = Vec::with_capacity(nodes.len() * 2);
+	let mut rest = Id::Iri(RDF_NIL.to_owned());
+	for (node, item) in nodes.into_iter().rev() {
+		triples.push(Triple(node.clone(), RDF_REST.to_owned(), Term::Id(rest)));
+		triples.push(Triple(node.clone(), RDF_FIRST.to_owned(), item));
+		rest = node
+	}
+
+	triples.reverse();
+	(rest, triples)
+}
+
+/// Decodes the RDF collection starting at `head`.
+///
+/// The `lookup` function must return the object of the given subject and
+/// predicate in the graph containing the list, if any.
+///
+/// Returns `None` if a node of the list is missing its `rdf:first` or
+/// `rdf:rest` property, if the rest of the list is a literal, or if the list
+/// is cyclic.
+pub fn decode<'a>(
+	head: &'a Subject,
+	mut lookup: impl FnMut(&Subject, &Iri) -> Option<&'a Object>,
+) -> Option<Vec<Object>> {
+	let mut items = Vec::new();
+	let mut visited = HashSet::new();
+	let mut node = head;
+
+	while !matches!(node, Id::Iri(iri) if iri.as_iri() == RDF_NIL) {
+		if !visited.insert(node) {
+			return None;
+		}
+
+		items.push(lookup(node, RDF_FIRST)?.clone());
+		node = lookup(node, RDF_REST)?.as_id()?;
+	}
+
+	Some(items)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{generator, Literal, LiteralType, XSD_STRING};
+
+	fn lookup<'a>(
+		triples: &'a [LexicalTriple],
+	) -> impl FnMut(&Subject, &Iri) -> Option<&'a Object> {
+		move |s: &Subject, p: &Iri| {
+			triples
+				.iter()
+				.find(|t| t.0 == *s && t.1.as_iri() == p)
+				.map(|t| &t.2)
+		}
+	}
+
+	#[test]
+	fn round_trip() {
+		let items: Vec<Object> = ["a", "b", "c"]
+			.into_iter()
+			.map(|v| {
+				Term::Literal(Literal::new(
+					v.to_owned(),
+					LiteralType::Any(XSD_STRING.to_owned()),
+				))
+			})
+			.collect();
+
+		let (head, triples) = encode(items.clone(), &mut generator::Blank::new());
+		assert_eq!(triples.len(), 6);
+		assert_eq!(decode(&head, lookup(&triples)), Some(items))
+	}
+
+	#[test]
+	fn empty() {
+		let (head, triples) = encode(Vec::new(), &mut generator::Blank::new());
+		let nil: Id = Id::Iri(RDF_NIL.to_owned());
+		assert_eq!(head, nil);
+		assert!(triples.is_empty());
+		assert_eq!(decode(&head, lookup(&triples)), Some(Vec::new()))
+	}
+
+	#[test]
+	fn cyclic() {
+		let (head, mut triples) = encode(
+			[Term::Id(Id::Iri(RDF_NIL.to_owned()))],
+			&mut generator::Blank::new(),
+		);
+		triples.retain(|t| t.1.as_iri() != RDF_REST);
+		triples.push(Triple(
+			head.clone(),
+			RDF_REST.to_owned(),
+			Term::Id(head.clone()),
+		));
+		assert_eq!(decode(&head, lookup(&triples)), None)
+	}
+}
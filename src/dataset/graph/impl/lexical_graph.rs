@@ -0,0 +1,249 @@
+use std::collections::{btree_set, BTreeSet, HashMap};
+
+use indexmap::IndexSet;
+use iref::IriBuf;
+
+use crate::{LexicalTriple, Object, Subject};
+
+/// In-memory set of lexical triples.
+///
+/// Unlike [`BTreeGraph`](super::BTreeGraph), whose triples are made of a
+/// single resource type, this graph directly stores
+/// `Triple<Subject, IriBuf, Object>` values. Triples are deduplicated and
+/// iterated in insertion order (unless some triples have been removed).
+///
+/// Triples are indexed by predicate, so that patterns with a bound predicate
+/// can be matched without scanning the whole graph.
+#[derive(Debug, Default, Clone)]
+pub struct LexicalGraph {
+	triples: IndexSet<LexicalTriple>,
+
+	/// Indexes of the triples, by predicate.
+	predicates: HashMap<IriBuf, BTreeSet<usize>>,
+}
+
+impl LexicalGraph {
+	/// Creates a new empty graph.
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Returns the number of triples in the graph.
+	pub fn len(&self) -> usize {
+		self.triples.len()
+	}
+
+	/// Checks if the graph is empty.
+	pub fn is_empty(&self) -> bool {
+		self.triples.is_empty()
+	}
+
+	/// Checks if the graph contains the given triple.
+	pub fn contains(&self, triple: &LexicalTriple) -> bool {
+		self.triples.contains(triple)
+	}
+
+	/// Inserts the given triple in the graph.
+	///
+	/// Returns `false` if the triple was already present.
+	pub fn insert(&mut self, triple: LexicalTriple) -> bool {
+		if self.triples.contains(&triple) {
+			false
+		} else {
+			let predicate = triple.1.clone();
+			let (i, _) = self.triples.insert_full(triple);
+			self.predicates.entry(predicate).or_default().insert(i);
+			true
+		}
+	}
+
+	/// Removes the given triple from the graph.
+	///
+	/// Returns `false` if the triple was not present.
+	pub fn remove(&mut self, triple: &LexicalTriple) -> bool {
+		match self.triples.swap_remove_full(triple) {
+			Some((i, removed)) => {
+				let indexes = self.predicates.get_mut(&removed.1).unwrap();
+				indexes.remove(&i);
+				if indexes.is_empty() {
+					self.predicates.remove(&removed.1);
+				}
+
+				// The last triple has been moved to index `i`.
+				let last = self.triples.len();
+				if i < last {
+					let indexes = self.predicates.get_mut(&self.triples[i].1).unwrap();
+					indexes.remove(&last);
+					indexes.insert(i);
+				}
+
+				true
+			}
+			None => false,
+		}
+	}
+
+	/// Returns an iterator over the triples of the graph.
+	pub fn iter(&self) -> indexmap::set::Iter<LexicalTriple> {
+		self.triples.iter()
+	}
+
+	/// Returns an iterator over the triples matching the given pattern.
+	///
+	/// Each `None` component is a wildcard matching any value. If the
+	/// predicate is given, only the triples with this predicate are visited.
+	pub fn match_triples<'a>(
+		&'a self,
+		subject: Option<&'a Subject>,
+		predicate: Option<&IriBuf>,
+		object: Option<&'a Object>,
+	) -> Matching<'a> {
+		let candidates = match predicate {
+			Some(predicate) => Candidates::Indexed(
+				&self.triples,
+				self.predicates
+					.get(predicate)
+					.map(BTreeSet::iter)
+					.unwrap_or_default(),
+			),
+			None => Candidates::All(self.triples.iter()),
+		};
+
+		Matching {
+			candidates,
+			subject,
+			object,
+		}
+	}
+}
+
+impl FromIterator<LexicalTriple> for LexicalGraph {
+	fn from_iter<T: IntoIterator<Item = LexicalTriple>>(iter: T) -> Self {
+		let mut result = Self::new();
+		result.extend(iter);
+		result
+	}
+}
+
+impl Extend<LexicalTriple> for LexicalGraph {
+	fn extend<T: IntoIterator<Item = LexicalTriple>>(&mut self, iter: T) {
+		for triple in iter {
+			self.insert(triple);
+		}
+	}
+}
+
+impl<'a> IntoIterator for &'a LexicalGraph {
+	type Item = &'a LexicalTriple;
+	type IntoIter = indexmap::set::Iter<'a, LexicalTriple>;
+
+	fn into_iter(self) -> Self::IntoIter {
+		self.iter()
+	}
+}
+
+impl IntoIterator for LexicalGraph {
+	type Item = LexicalTriple;
+	type IntoIter = indexmap::set::IntoIter<LexicalTriple>;
+
+	fn into_iter(self) -> Self::IntoIter {
+		self.triples.into_iter()
+	}
+}
+
+/// Iterator over the triples of a [`LexicalGraph`] matching a pattern.
+///
+/// See [`LexicalGraph::match_triples`].
+pub struct Matching<'a> {
+	candidates: Candidates<'a>,
+	subject: Option<&'a Subject>,
+	object: Option<&'a Object>,
+}
+
+enum Candidates<'a> {
+	All(indexmap::set::Iter<'a, LexicalTriple>),
+	Indexed(&'a IndexSet<LexicalTriple>, btree_set::Iter<'a, usize>),
+}
+
+impl<'a> Iterator for Candidates<'a> {
+	type Item = &'a LexicalTriple;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		match self {
+			Self::All(iter) => iter.next(),
+			Self::Indexed(triples, indexes) => indexes.next().map(|&i| &triples[i]),
+		}
+	}
+}
+
+impl<'a> Iterator for Matching<'a> {
+	type Item = &'a LexicalTriple;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		let (subject, object) = (self.subject, self.object);
+		self.candidates
+			.find(|t| subject.map_or(true, |s| t.0 == *s) && object.map_or(true, |o| t.2 == *o))
+	}
+}
+
+impl PartialEq for LexicalGraph {
+	fn eq(&self, other: &Self) -> bool {
+		self.len() == other.len() && self.iter().all(|t| other.contains(t))
+	}
+}
+
+impl Eq for LexicalGraph {}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn triple(s: &str) -> LexicalTriple {
+		s.parse().unwrap()
+	}
+
+	#[test]
+	fn set_operations() {
+		let a = triple("_:a <http://example.org/#p> \"a\" .");
+		let b = triple("_:b <http://example.org/#p> _:a .");
+
+		let mut graph: LexicalGraph = [a.clone(), b.clone(), a.clone()].into_iter().collect();
+		assert_eq!(graph.len(), 2);
+		assert!(graph.contains(&a));
+
+		assert!(graph.remove(&a));
+		assert!(!graph.remove(&a));
+		assert!(!graph.contains(&a));
+		assert_eq!(graph.iter().collect::<Vec<_>>(), [&b]);
+
+		graph.extend([a.clone()]);
+		assert_eq!(graph, [a, b].into_iter().collect())
+	}
+
+	#[test]
+	fn pattern_matching() {
+		let mut graph: LexicalGraph = [
+			"_:a <http://example.org/#p> \"a\" .",
+			"_:b <http://example.org/#p> _:a .",
+			"_:b <http://example.org/#q> _:a .",
+			"_:c <http://example.org/#q> _:c .",
+		]
+		.into_iter()
+		.map(triple)
+		.collect();
+
+		graph.remove(&triple("_:a <http://example.org/#p> \"a\" ."));
+
+		let p = triple("_:b <http://example.org/#p> _:a .").1;
+		let q = triple("_:b <http://example.org/#q> _:a .");
+		assert_eq!(graph.match_triples(None, Some(&p), None).count(), 1);
+		assert_eq!(graph.match_triples(Some(&q.0), None, None).count(), 2);
+		assert_eq!(
+			graph
+				.match_triples(None, Some(&q.1), Some(&q.2))
+				.collect::<Vec<_>>(),
+			[&q]
+		);
+		assert_eq!(graph.match_triples(None, None, None).count(), 3)
+	}
+}
@@ -0,0 +1,221 @@
+use std::collections::{hash_map, HashMap};
+
+use iref::IriBuf;
+
+use crate::{
+	dataset::LexicalGraph, GraphLabel, LexicalQuad, LexicalTriple, Object, Quad, Subject, Triple,
+};
+
+/// In-memory set of lexical quads, stored as one [`LexicalGraph`] per graph.
+///
+/// The default graph always exists, even if it is empty. Named graphs are
+/// removed as soon as they become empty.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct LexicalDataset {
+	default_graph: LexicalGraph,
+	named_graphs: HashMap<GraphLabel, LexicalGraph>,
+}
+
+impl LexicalDataset {
+	/// Creates a new empty dataset.
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Returns the number of quads in the dataset.
+	pub fn len(&self) -> usize {
+		self.named_graphs
+			.values()
+			.fold(self.default_graph.len(), |len, graph| len + graph.len())
+	}
+
+	/// Checks if the dataset is empty.
+	pub fn is_empty(&self) -> bool {
+		self.default_graph.is_empty() && self.named_graphs.is_empty()
+	}
+
+	/// Returns the default graph.
+	pub fn default_graph(&self) -> &LexicalGraph {
+		&self.default_graph
+	}
+
+	/// Returns the graph with the given label, or the default graph if no
+	/// label is given.
+	///
+	/// Returns `None` if there is no quad in the given named graph.
+	pub fn graph(&self, label: Option<&GraphLabel>) -> Option<&LexicalGraph> {
+		match label {
+			Some(label) => self.named_graphs.get(label),
+			None => Some(&self.default_graph),
+		}
+	}
+
+	/// Returns an iterator over the named graphs of the dataset.
+	pub fn named_graphs(&self) -> hash_map::Iter<GraphLabel, LexicalGraph> {
+		self.named_graphs.iter()
+	}
+
+	/// Checks if the dataset contains the given quad.
+	pub fn contains(&self, Quad(s, p, o, g): &LexicalQuad) -> bool {
+		self.graph(g.as_ref())
+			.is_some_and(|graph| graph.contains(&Triple(s.clone(), p.clone(), o.clone())))
+	}
+
+	/// Inserts the given quad in the dataset.
+	///
+	/// Returns `false` if the quad was already present.
+	pub fn insert(&mut self, quad: LexicalQuad) -> bool {
+		let (triple, graph) = quad.into_triple();
+		match graph {
+			Some(label) => self.named_graphs.entry(label).or_default().insert(triple),
+			None => self.default_graph.insert(triple),
+		}
+	}
+
+	/// Removes the given quad from the dataset.
+	///
+	/// Returns `false` if the quad was not present.
+	pub fn remove(&mut self, Quad(s, p, o, g): &LexicalQuad) -> bool {
+		let triple = Triple(s.clone(), p.clone(), o.clone());
+		match g {
+			Some(label) => match self.named_graphs.get_mut(label) {
+				Some(graph) => {
+					let removed = graph.remove(&triple);
+					if graph.is_empty() {
+						self.named_graphs.remove(label);
+					}
+
+					removed
+				}
+				None => false,
+			},
+			None => self.default_graph.remove(&triple),
+		}
+	}
+
+	/// Returns an iterator over the quads of the dataset, starting with the
+	/// quads of the default graph.
+	pub fn quads(&self) -> Quads {
+		Quads {
+			current: Some((None, self.default_graph.iter())),
+			named_graphs: self.named_graphs.iter(),
+		}
+	}
+}
+
+impl FromIterator<LexicalQuad> for LexicalDataset {
+	fn from_iter<T: IntoIterator<Item = LexicalQuad>>(iter: T) -> Self {
+		let mut result = Self::new();
+		result.extend(iter);
+		result
+	}
+}
+
+impl Extend<LexicalQuad> for LexicalDataset {
+	fn extend<T: IntoIterator<Item = LexicalQuad>>(&mut self, iter: T) {
+		for quad in iter {
+			self.insert(quad);
+		}
+	}
+}
+
+impl<'a> IntoIterator for &'a LexicalDataset {
+	type Item = Quad<&'a Subject, &'a IriBuf, &'a Object, &'a GraphLabel>;
+	type IntoIter = Quads<'a>;
+
+	fn into_iter(self) -> Self::IntoIter {
+		self.quads()
+	}
+}
+
+/// Iterator over the quads of a [`LexicalDataset`].
+///
+/// See [`LexicalDataset::quads`].
+pub struct Quads<'a> {
+	current: Option<(
+		Option<&'a GraphLabel>,
+		indexmap::set::Iter<'a, LexicalTriple>,
+	)>,
+	named_graphs: hash_map::Iter<'a, GraphLabel, LexicalGraph>,
+}
+
+impl<'a> Iterator for Quads<'a> {
+	type Item = Quad<&'a Subject, &'a IriBuf, &'a Object, &'a GraphLabel>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		loop {
+			if let Some((g, triples)) = &mut self.current {
+				if let Some(Triple(s, p, o)) = triples.next() {
+					break Some(Quad(s, p, o, *g));
+				}
+			}
+
+			match self.named_graphs.next() {
+				Some((g, graph)) => self.current = Some((Some(g), graph.iter())),
+				None => {
+					self.current = None;
+					break None;
+				}
+			}
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn quad(s: &str) -> LexicalQuad {
+		s.parse().unwrap()
+	}
+
+	#[test]
+	fn default_graph() {
+		let a = quad("_:a <http://example.org/#p> \"a\" .");
+		let b = quad("_:b <http://example.org/#p> _:a <http://example.org/#g> .");
+
+		let mut dataset = LexicalDataset::new();
+		assert!(dataset.is_empty());
+		assert!(dataset.graph(None).unwrap().is_empty());
+
+		assert!(dataset.insert(a.clone()));
+		assert!(!dataset.insert(a.clone()));
+		assert!(dataset.insert(b.clone()));
+		assert_eq!(dataset.len(), 2);
+		assert!(dataset.contains(&a));
+		assert_eq!(dataset.default_graph().len(), 1);
+		assert_eq!(dataset.named_graphs().count(), 1);
+
+		let g = b.3.clone().unwrap();
+		assert_eq!(dataset.graph(Some(&g)).unwrap().len(), 1);
+		assert!(!dataset.contains(&a.clone().with_graph(Some(g.clone()))));
+
+		assert!(dataset.remove(&b));
+		assert!(dataset.graph(Some(&g)).is_none());
+		assert!(dataset.remove(&a));
+		assert!(dataset.is_empty());
+		assert!(dataset.graph(None).is_some())
+	}
+
+	#[test]
+	fn quads() {
+		let quads = [
+			quad("_:a <http://example.org/#p> \"a\" ."),
+			quad("_:b <http://example.org/#p> _:a <http://example.org/#g> ."),
+			quad("_:b <http://example.org/#q> _:a <http://example.org/#g> ."),
+			quad("_:c <http://example.org/#q> _:c _:h ."),
+		];
+
+		let dataset: LexicalDataset = quads.iter().cloned().collect();
+		let mut collected: Vec<LexicalQuad> = dataset
+			.quads()
+			.map(|Quad(s, p, o, g)| Quad(s.clone(), p.clone(), o.clone(), g.cloned()))
+			.collect();
+		assert_eq!(collected[0], quads[0]);
+
+		collected.sort();
+		let mut expected = quads.to_vec();
+		expected.sort();
+		assert_eq!(collected, expected)
+	}
+}
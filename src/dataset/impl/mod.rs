@@ -1,5 +1,7 @@
 pub mod btree_dataset;
 pub mod indexed_btree_dataset;
+pub mod lexical_dataset;
 
 pub use btree_dataset::BTreeDataset;
 pub use indexed_btree_dataset::IndexedBTreeDataset;
+pub use lexical_dataset::LexicalDataset;
@@ -5,7 +5,7 @@ use educe::Educe;
 use crate::{
 	dataset::TraversableDataset,
 	interpretation::{ReverseIriInterpretation, ReverseLiteralInterpretation},
-	Quad, Term,
+	BlankIdBuf, Id, LexicalTriple, Quad, Term, Triple,
 };
 
 /// Checks that there is an isomorphism between the datasets `a` and `b`.
@@ -131,6 +131,120 @@ where
 	)
 }
 
+/// Checks that the lexical graphs `a` and `b` are isomorphic, that is equal
+/// up to blank node relabeling.
+///
+/// Duplicate triples are ignored. Ground (blank-free) triples are compared
+/// first, then blank node bijections are searched by backtracking. This is
+/// exponential in the number of blank nodes in the worst case, and meant for
+/// graphs with a handful of blank nodes, such as test assertions.
+pub fn graph_isomorphic(a: &[LexicalTriple], b: &[LexicalTriple]) -> bool {
+	let (a_ground, a_triples) = split_ground(a);
+	let (b_ground, b_triples) = split_ground(b);
+
+	if a_ground != b_ground || a_triples.len() != b_triples.len() {
+		return false;
+	}
+
+	let a_blanks = blank_degrees(&a_triples);
+	let b_blanks = blank_degrees(&b_triples);
+
+	if a_blanks.len() != b_blanks.len() {
+		return false;
+	}
+
+	let a_blanks: Vec<_> = a_blanks.into_iter().collect();
+	find_graph_bijection(
+		&a_triples,
+		&b_triples,
+		&a_blanks,
+		&b_blanks,
+		&mut BTreeMap::new(),
+	)
+}
+
+/// Splits the given triples into ground triples and triples containing blank
+/// nodes.
+fn split_ground(triples: &[LexicalTriple]) -> (BTreeSet<&LexicalTriple>, BTreeSet<&LexicalTriple>) {
+	triples
+		.iter()
+		.partition(|t| triple_blank_ids(t).next().is_none())
+}
+
+fn triple_blank_ids(triple: &LexicalTriple) -> impl Iterator<Item = &BlankIdBuf> {
+	triple
+		.0
+		.as_blank()
+		.into_iter()
+		.chain(triple.2.as_id().and_then(Id::as_blank))
+}
+
+/// Counts the occurrences of each blank node in the given triples.
+fn blank_degrees<'a>(triples: &BTreeSet<&'a LexicalTriple>) -> BTreeMap<&'a BlankIdBuf, usize> {
+	let mut degrees = BTreeMap::new();
+	for t in triples.iter().copied() {
+		for b in triple_blank_ids(t) {
+			*degrees.entry(b).or_default() += 1
+		}
+	}
+
+	degrees
+}
+
+fn find_graph_bijection<'a, 'b>(
+	a: &BTreeSet<&'a LexicalTriple>,
+	b: &BTreeSet<&'b LexicalTriple>,
+	a_blanks: &[(&'a BlankIdBuf, usize)],
+	b_blanks: &BTreeMap<&'b BlankIdBuf, usize>,
+	bijection: &mut BTreeMap<&'a BlankIdBuf, &'b BlankIdBuf>,
+) -> bool {
+	match a_blanks.split_first() {
+		Some(((blank, degree), rest)) => {
+			for (candidate, candidate_degree) in b_blanks {
+				if candidate_degree == degree && !bijection.values().any(|c| c == candidate) {
+					bijection.insert(blank, candidate);
+
+					let consistent = a.iter().all(|t| match map_triple(t, bijection) {
+						Some(mapped) => b.contains(&mapped),
+						None => true,
+					});
+
+					if consistent && find_graph_bijection(a, b, rest, b_blanks, bijection) {
+						return true;
+					}
+
+					bijection.remove(blank);
+				}
+			}
+
+			false
+		}
+		None => true,
+	}
+}
+
+/// Maps the blank nodes of the given triple through the given bijection.
+///
+/// Returns `None` if some blank node is not yet mapped.
+fn map_triple(
+	triple: &LexicalTriple,
+	bijection: &BTreeMap<&BlankIdBuf, &BlankIdBuf>,
+) -> Option<LexicalTriple> {
+	let id = |id: &Id| match id {
+		Id::Blank(b) => bijection.get(b).map(|b| Id::Blank((*b).clone())),
+		Id::Iri(i) => Some(Id::Iri(i.clone())),
+	};
+
+	Some(Triple(
+		id(&triple.0)?,
+		triple.1.clone(),
+		match &triple.2 {
+			Term::Id(i) => Term::Id(id(i)?),
+			Term::Literal(l) => Term::Literal(l.clone()),
+		},
+	))
+}
+
 fn resource_matches<I>(interpretation: &I, a: &I::Resource, b: &I::Resource) -> bool
 where
 	I: ReverseIriInterpretation + ReverseLiteralInterpretation,
@@ -464,3 +578,35 @@ impl<'a, R> BlankSignature<'a, R> {
 		}
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn parse(document: &str) -> Vec<LexicalTriple> {
+		document.lines().map(|line| line.parse().unwrap()).collect()
+	}
+
+	#[test]
+	fn graph_isomorphism() {
+		let a = parse(
+			"_:a <http://example.org/#p> _:b .\n\
+			_:b <http://example.org/#p> \"v\" .\n\
+			<http://example.org/#s> <http://example.org/#p> _:a .",
+		);
+		let b = parse(
+			"_:y <http://example.org/#p> \"v\" .\n\
+			<http://example.org/#s> <http://example.org/#p> _:x .\n\
+			_:x <http://example.org/#p> _:y .",
+		);
+		let c = parse(
+			"_:y <http://example.org/#p> \"v\" .\n\
+			<http://example.org/#s> <http://example.org/#p> _:y .\n\
+			_:x <http://example.org/#p> _:y .",
+		);
+
+		assert!(graph_isomorphic(&a, &b));
+		assert!(!graph_isomorphic(&a, &c));
+		assert!(!graph_isomorphic(&a, &a[1..]))
+	}
+}
@@ -0,0 +1,18 @@
+//! Common literal datatype IRIs.
+//!
+//! This module gathers the IRIs of the most common XSD and RDF datatypes, so
+//! they can be used to build typed literals without parsing any IRI:
+//!
+//! ```
+//! use rdf_types::{datatype, Literal, LiteralType};
+//!
+//! let literal = Literal::new(
+//!     "42".to_owned(),
+//!     LiteralType::Any(datatype::XSD_INTEGER.to_owned())
+//! );
+//! ```
+pub use crate::{
+	RDF_HTML, RDF_JSON, RDF_LANG_STRING, RDF_XML_LITERAL, XSD_ANY_URI, XSD_BASE64_BINARY,
+	XSD_BOOLEAN, XSD_DATE, XSD_DATE_TIME, XSD_DECIMAL, XSD_DOUBLE, XSD_FLOAT, XSD_HEX_BINARY,
+	XSD_INTEGER, XSD_STRING, XSD_TIME,
+};
@@ -14,6 +14,8 @@ pub trait RdfDisplay {
 	}
 }
 
+/// Formats the string as a quoted literal value, using the canonical
+/// N-Triples escape sequences.
 impl RdfDisplay for str {
 	fn rdf_fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
 		use fmt::Display;
@@ -25,6 +27,10 @@ impl RdfDisplay for str {
 				'\\' => write!(f, "\\\\"),
 				'\n' => write!(f, "\\n"),
 				'\r' => write!(f, "\\r"),
+				'\t' => write!(f, "\\t"),
+				'\x08' => write!(f, "\\b"),
+				'\x0c' => write!(f, "\\f"),
+				'\x00'..='\x1f' | '\x7f' => write!(f, "\\u{:04X}", c as u32),
 				c => c.fmt(f),
 			}?
 		}
@@ -39,20 +45,32 @@ impl RdfDisplay for String {
 	}
 }
 
-impl RdfDisplay for iref::IriRef {
-	fn rdf_fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-		write!(f, "<")?;
+/// Writer escaping the body of an IRI according to the N-Triples `IRIREF`
+/// production.
+///
+/// Characters that cannot appear in an `IRIREF` are written as `\uXXXX`
+/// escape sequences. The enclosing angle brackets are not written.
+pub(crate) struct IriEscaper<'a, 'b>(pub &'a mut fmt::Formatter<'b>);
 
-		for c in self.as_str().chars() {
+impl<'a, 'b> fmt::Write for IriEscaper<'a, 'b> {
+	fn write_str(&mut self, s: &str) -> fmt::Result {
+		for c in s.chars() {
 			match c {
 				'\x00'..='\x20' | '<' | '>' | '"' | '{' | '}' | '|' | '^' | '`' | '\\' => {
-					let bytes: u32 = c.into();
-					write!(f, "\\u{bytes:#04x}")
+					write!(self.0, "\\u{:04X}", c as u32)
 				}
-				_ => fmt::Display::fmt(&c, f),
+				_ => fmt::Write::write_char(self.0, c),
 			}?;
 		}
 
+		Ok(())
+	}
+}
+
+impl RdfDisplay for iref::IriRef {
+	fn rdf_fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "<")?;
+		fmt::Write::write_str(&mut IriEscaper(f), self.as_str())?;
 		write!(f, ">")
 	}
 }
@@ -134,6 +152,19 @@ impl<'a, T: RdfDisplay + ?Sized> RdfDisplay for &'a T {
 	}
 }
 
+/// Formats the optional value, writing nothing for `None`.
+///
+/// Useful to format the optional graph component of a quad.
+impl<T: RdfDisplay> RdfDisplay for Option<T> {
+	#[inline(always)]
+	fn rdf_fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			Some(t) => t.rdf_fmt(f),
+			None => Ok(()),
+		}
+	}
+}
+
 /// Value ready to be formatted as an RDF syntax element.
 pub struct RdfDisplayed<T>(T);
 
@@ -157,6 +188,17 @@ impl<'a, T: RdfDisplayWithContext<C> + ?Sized, C: ?Sized> RdfDisplayWithContext<
 	}
 }
 
+#[cfg(feature = "contextual")]
+impl<T: RdfDisplayWithContext<C>, C: ?Sized> RdfDisplayWithContext<C> for Option<T> {
+	#[inline(always)]
+	fn rdf_fmt_with(&self, context: &C, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			Some(t) => t.rdf_fmt_with(context, f),
+			None => Ok(()),
+		}
+	}
+}
+
 #[cfg(feature = "contextual")]
 impl<'c, T: RdfDisplayWithContext<C>, C: ?Sized> RdfDisplay for contextual::Contextual<T, &'c C> {
 	#[inline(always)]
@@ -1,8 +1,10 @@
 //! Resource identifier generators.
 use crate::{
-	vocabulary::{BlankIdVocabulary, BlankIdVocabularyMut, IriVocabulary},
+	vocabulary::{BlankIdVocabulary, BlankIdVocabularyMut, IriVocabulary, IriVocabularyMut},
 	BlankIdBuf, Id, Vocabulary,
 };
+use iref::{InvalidIri, Iri, IriBuf};
+use std::sync::atomic::{self, AtomicUsize};
 
 /// Subject identifier generator.
 pub trait Generator<V: IriVocabulary + BlankIdVocabulary = ()> {
@@ -96,16 +98,229 @@ impl Blank {
 		self.count
 	}
 
+	/// Returns the blank node identifier that will be generated next, without
+	/// advancing the generator.
+	///
+	/// Calling [`Self::next_blank_id`] right after `peek_blank_id` returns the
+	/// same identifier, which can be used to register the identifier
+	/// somewhere else before generating it.
+	pub fn peek_blank_id(&self) -> BlankIdBuf {
+		unsafe { BlankIdBuf::from_fmt_unchecked(format_args!("_:{}{}", self.prefix, self.count)) }
+	}
+
 	pub fn next_blank_id(&mut self) -> BlankIdBuf {
-		let id = unsafe { BlankIdBuf::new_unchecked(format!("_:{}{}", self.prefix, self.count)) };
+		let id = self.peek_blank_id();
 		self.count += 1;
 		id
 	}
+
+	/// Generates the next blank node identifier, or returns `None` if the
+	/// generator is exhausted instead of panicking.
+	pub fn try_next_blank_id(&mut self) -> Option<BlankIdBuf> {
+		let count = self.count.checked_add(1)?;
+		let id = self.peek_blank_id();
+		self.count = count;
+		Some(id)
+	}
+
+	/// Generates the next fresh node identifier in the given vocabulary, or
+	/// returns `None` if the generator is exhausted.
+	///
+	/// This is the non-panicking counterpart of [`Generator::next`].
+	pub fn try_next<V: Vocabulary + BlankIdVocabularyMut>(
+		&mut self,
+		vocabulary: &mut V,
+	) -> Option<Id<V::Iri, V::BlankId>> {
+		self.try_next_blank_id()
+			.map(|id| Id::Blank(vocabulary.insert_owned_blank_id(id)))
+	}
 }
 
 impl<V: Vocabulary + BlankIdVocabularyMut> Generator<V> for Blank {
 	fn next(&mut self, vocabulary: &mut V) -> Id<V::Iri, V::BlankId> {
-		Id::Blank(vocabulary.insert_blank_id(&self.next_blank_id()))
+		Id::Blank(vocabulary.insert_owned_blank_id(self.next_blank_id()))
+	}
+}
+
+/// Thread-safe numbered blank node identifier generator,
+/// with an optional prefix.
+///
+/// Same as [`Blank`], but identifiers can be generated through a shared
+/// reference thanks to an atomic counter, so that a single generator can be
+/// shared between threads.
+#[derive(Default)]
+pub struct AtomicBlank {
+	/// Prefix string.
+	prefix: String,
+
+	/// Number of already generated identifiers.
+	count: AtomicUsize,
+}
+
+impl AtomicBlank {
+	/// Creates a new numbered generator with no prefix.
+	pub fn new() -> Self {
+		Self::new_full(String::new(), 0)
+	}
+
+	/// Creates a new numbered generator with no prefix,
+	/// starting with the given `offset` number.
+	pub fn new_with_offset(offset: usize) -> Self {
+		Self::new_full(String::new(), offset)
+	}
+
+	/// Creates a new numbered generator with the given prefix.
+	pub fn new_with_prefix(prefix: String) -> Self {
+		Self::new_full(prefix, 0)
+	}
+
+	/// Creates a new numbered generator with the given prefix,
+	/// starting with the given `offset` number.
+	pub fn new_full(prefix: String, offset: usize) -> Self {
+		Self {
+			prefix,
+			count: AtomicUsize::new(offset),
+		}
+	}
+
+	/// Returns the prefix of this generator.
+	pub fn prefix(&self) -> &str {
+		&self.prefix
+	}
+
+	/// Returns the number of already generated identifiers.
+	pub fn count(&self) -> usize {
+		self.count.load(atomic::Ordering::Relaxed)
+	}
+
+	pub fn next_blank_id(&self) -> BlankIdBuf {
+		let n = self.count.fetch_add(1, atomic::Ordering::Relaxed);
+		unsafe { BlankIdBuf::from_fmt_unchecked(format_args!("_:{}{}", self.prefix, n)) }
+	}
+}
+
+impl<V: Vocabulary + BlankIdVocabularyMut> Generator<V> for AtomicBlank {
+	fn next(&mut self, vocabulary: &mut V) -> Id<V::Iri, V::BlankId> {
+		Id::Blank(vocabulary.insert_owned_blank_id(self.next_blank_id()))
+	}
+}
+
+impl<'a, V: Vocabulary + BlankIdVocabularyMut> Generator<V> for &'a AtomicBlank {
+	fn next(&mut self, vocabulary: &mut V) -> Id<V::Iri, V::BlankId> {
+		Id::Blank(vocabulary.insert_owned_blank_id(self.next_blank_id()))
+	}
+}
+
+/// Generates numbered IRIs under a base namespace.
+///
+/// Each generated IRI is the base IRI followed by the number of already
+/// generated identifiers, such as `http://example.org/ns/0`,
+/// `http://example.org/ns/1`, etc.
+pub struct Namespaced {
+	/// Base IRI.
+	base: IriBuf,
+
+	/// Number of already generated identifiers.
+	count: usize,
+}
+
+impl Namespaced {
+	/// Creates a new numbered IRI generator under the given base IRI.
+	///
+	/// Fails if the base IRI followed by a number is not a valid IRI.
+	pub fn new(base: IriBuf) -> Result<Self, InvalidIri<String>> {
+		Self::new_with_offset(base, 0)
+	}
+
+	/// Creates a new numbered IRI generator under the given base IRI,
+	/// starting with the given `offset` number.
+	///
+	/// Fails if the base IRI followed by a number is not a valid IRI.
+	pub fn new_with_offset(base: IriBuf, offset: usize) -> Result<Self, InvalidIri<String>> {
+		IriBuf::new(format!("{base}{offset}"))?;
+		Ok(Self {
+			base,
+			count: offset,
+		})
+	}
+
+	/// Returns the base IRI of this generator.
+	pub fn base(&self) -> &Iri {
+		&self.base
+	}
+
+	/// Returns the number of already generated identifiers.
+	pub fn count(&self) -> usize {
+		self.count
+	}
+
+	pub fn next_iri(&mut self) -> IriBuf {
+		let iri = unsafe { IriBuf::new_unchecked(format!("{}{}", self.base, self.count)) };
+		self.count += 1;
+		iri
+	}
+}
+
+impl<V: Vocabulary + IriVocabularyMut> Generator<V> for Namespaced {
+	fn next(&mut self, vocabulary: &mut V) -> Id<V::Iri, V::BlankId> {
+		Id::Iri(vocabulary.insert_owned(self.next_iri()))
+	}
+}
+
+/// Generator adapter recording every generated identifier.
+///
+/// This is a diagnostic tool: it delegates to the inner generator and keeps a
+/// copy of every identifier it produces, accessible using
+/// [`Recording::recorded`].
+pub struct Recording<G, I = IriBuf, B = BlankIdBuf> {
+	/// Inner generator.
+	generator: G,
+
+	/// Generated identifiers, in order.
+	recorded: Vec<Id<I, B>>,
+}
+
+impl<G, I, B> Recording<G, I, B> {
+	/// Wraps the given generator.
+	pub fn new(generator: G) -> Self {
+		Self {
+			generator,
+			recorded: Vec::new(),
+		}
+	}
+
+	/// Returns the inner generator.
+	pub fn generator(&self) -> &G {
+		&self.generator
+	}
+
+	/// Returns every identifier generated so far, in order.
+	pub fn recorded(&self) -> &[Id<I, B>] {
+		&self.recorded
+	}
+
+	/// Clears the recorded identifiers.
+	pub fn clear(&mut self) {
+		self.recorded.clear()
+	}
+
+	/// Turns this adapter into the inner generator and the recorded
+	/// identifiers.
+	pub fn into_parts(self) -> (G, Vec<Id<I, B>>) {
+		(self.generator, self.recorded)
+	}
+}
+
+impl<V: IriVocabulary + BlankIdVocabulary, G: Generator<V>> Generator<V>
+	for Recording<G, V::Iri, V::BlankId>
+where
+	V::Iri: Clone,
+	V::BlankId: Clone,
+{
+	fn next(&mut self, vocabulary: &mut V) -> Id<V::Iri, V::BlankId> {
+		let id = self.generator.next(vocabulary);
+		self.recorded.push(id.clone());
+		id
 	}
 }
 
@@ -140,12 +355,20 @@ pub enum Uuid {
 	/// See [uuid::Uuid::new_v5] for more information.
 	#[cfg(feature = "uuid-generator-v5")]
 	V5(uuid::Uuid, String),
+
+	/// UUIDv7.
+	///
+	/// Time-ordered UUID, suitable for sortable identifiers.
+	/// See [uuid::Uuid::now_v7] for more information.
+	#[cfg(feature = "uuid-generator-v7")]
+	V7,
 }
 
 #[cfg(any(
 	feature = "uuid-generator-v3",
 	feature = "uuid-generator-v4",
-	feature = "uuid-generator-v5"
+	feature = "uuid-generator-v5",
+	feature = "uuid-generator-v7"
 ))]
 impl Uuid {
 	pub fn next_uuid(&self) -> uuid::Uuid {
@@ -156,6 +379,8 @@ impl Uuid {
 			Self::V4 => uuid::Uuid::new_v4(),
 			#[cfg(feature = "uuid-generator-v5")]
 			Self::V5(vocabulary, name) => uuid::Uuid::new_v5(vocabulary, name.as_bytes()),
+			#[cfg(feature = "uuid-generator-v7")]
+			Self::V7 => uuid::Uuid::now_v7(),
 		}
 	}
 
@@ -187,13 +412,14 @@ impl Uuid {
 #[cfg(any(
 	feature = "uuid-generator-v3",
 	feature = "uuid-generator-v4",
-	feature = "uuid-generator-v5"
+	feature = "uuid-generator-v5",
+	feature = "uuid-generator-v7"
 ))]
 impl<V: crate::Vocabulary + crate::vocabulary::IriVocabularyMut> Generator<V> for Uuid {
 	fn next(&mut self, vocabulary: &mut V) -> Id<V::Iri, V::BlankId> {
-		let mut buffer: Vec<u8> = vec![0; uuid::adapter::Urn::LENGTH];
+		let mut buffer: Vec<u8> = vec![0; uuid::fmt::Urn::LENGTH];
 		let uuid = self.next_uuid();
-		let len = uuid.to_urn().encode_lower(buffer.as_mut()).len();
+		let len = uuid.urn().encode_lower(buffer.as_mut()).len();
 		buffer.truncate(len);
 
 		Id::Iri(vocabulary.insert_owned(unsafe {
@@ -202,15 +428,82 @@ impl<V: crate::Vocabulary + crate::vocabulary::IriVocabularyMut> Generator<V> fo
 	}
 }
 
-#[cfg(any(
-	feature = "uuid-generator-v3",
-	feature = "uuid-generator-v4",
-	feature = "uuid-generator-v5"
-))]
 #[cfg(test)]
 mod tests {
 	use super::*;
 
+	#[test]
+	fn recording() {
+		let mut generator = Recording::new(Blank::new_with_prefix("b".to_string()));
+		let ids: Vec<Id> = (0..3).map(|_| generator.next(&mut ())).collect();
+		assert_eq!(generator.recorded(), ids);
+		let expected: [Id; 3] =
+			["_:b0", "_:b1", "_:b2"].map(|b| Id::Blank(BlankIdBuf::new(b.to_string()).unwrap()));
+		assert_eq!(generator.recorded(), expected);
+		assert_eq!(generator.generator().count(), 3)
+	}
+
+	#[test]
+	fn peek() {
+		let mut generator = Blank::new_with_prefix("b".to_string());
+		let peeked = generator.peek_blank_id();
+		assert_eq!(generator.count(), 0);
+		assert_eq!(generator.next_blank_id(), peeked);
+		assert_eq!(peeked.as_str(), "_:b0");
+		assert_eq!(generator.peek_blank_id().as_str(), "_:b1")
+	}
+
+	#[test]
+	fn try_next() {
+		let mut generator = Blank::new_with_offset(usize::MAX - 1);
+		let id: Option<Id> = generator.try_next(&mut ());
+		assert_eq!(
+			id,
+			Some(Id::Blank(
+				BlankIdBuf::new(format!("_:{}", usize::MAX - 1)).unwrap()
+			))
+		);
+		assert_eq!(generator.count(), usize::MAX);
+		assert_eq!(generator.try_next_blank_id(), None);
+		assert_eq!(generator.count(), usize::MAX)
+	}
+
+	#[test]
+	fn atomic_blank() {
+		let generator = AtomicBlank::new_with_prefix("b".to_string());
+		let mut ids: Vec<Id> = std::thread::scope(|s| {
+			let handles: Vec<_> = (0..4)
+				.map(|_| {
+					s.spawn(|| {
+						(0..25)
+							.map(|_| (&generator).next(&mut ()))
+							.collect::<Vec<_>>()
+					})
+				})
+				.collect();
+			handles
+				.into_iter()
+				.flat_map(|h| h.join().unwrap())
+				.collect()
+		});
+
+		ids.sort();
+		ids.dedup();
+		assert_eq!(ids.len(), 100);
+		assert_eq!(generator.count(), 100)
+	}
+
+	#[test]
+	fn namespaced() {
+		let mut generator =
+			Namespaced::new(static_iref::iri!("http://example.org/ns/").to_owned()).unwrap();
+		let ids: Vec<Id> = (0..2).map(|_| generator.next(&mut ())).collect();
+		let expected: [Id; 2] = ["http://example.org/ns/0", "http://example.org/ns/1"]
+			.map(|iri| Id::Iri(IriBuf::new(iri.to_owned()).unwrap()));
+		assert_eq!(ids, expected);
+		assert_eq!(generator.count(), 2)
+	}
+
 	#[cfg(feature = "uuid-generator-v3")]
 	#[test]
 	fn uuidv3_iri() {
@@ -234,6 +527,16 @@ mod tests {
 		}
 	}
 
+	#[cfg(feature = "uuid-generator-v7")]
+	#[test]
+	fn uuidv7_iri() {
+		let mut uuid_gen = Uuid::V7;
+		for _ in 0..100 {
+			let reference: Id = uuid_gen.next(&mut ());
+			assert!(iref::Iri::new(reference.as_str()).is_ok())
+		}
+	}
+
 	#[cfg(feature = "uuid-generator-v5")]
 	#[test]
 	fn uuidv5_iri() {
@@ -5,12 +5,45 @@ use crate::{Id, Literal, Quad, Term, Triple};
 /// A quad where each component is a [`Term`].
 pub type GrdfQuad<I = Id, L = Literal> = Quad<Term<I, L>>;
 
+/// RDF quad, whose components can be lifted into a gRDF quad.
+///
+/// Works with any IRI, blank node identifier and literal type, including
+/// vocabulary-indexed ones.
 impl<I, B, L> Quad<Id<I, B>, I, Term<Id<I, B>, L>, Id<I, B>> {
 	pub fn into_grdf(self) -> GrdfQuad<Id<I, B>, L> {
 		self.map_subject(|s| Term::Id(s))
 			.map_predicate(|p| Term::Id(Id::Iri(p)))
 			.map_graph(|g| g.map(Term::Id))
 	}
+
+	/// Borrows the quad as a gRDF quad.
+	pub fn as_grdf(&self) -> GrdfQuad<Id<&I, &B>, &L> {
+		Quad(
+			Term::Id(self.0.as_ref()),
+			Term::Id(Id::Iri(&self.1)),
+			self.2.as_ref().map_id(Id::as_ref),
+			self.3.as_ref().map(|g| Term::Id(g.as_ref())),
+		)
+	}
+}
+
+/// Generalized RDF quad, where the predicate may be a blank node identifier.
+impl<I, B, L> Quad<Id<I, B>, Id<I, B>, Term<Id<I, B>, L>, Id<I, B>> {
+	pub fn into_grdf(self) -> GrdfQuad<Id<I, B>, L> {
+		self.map_subject(|s| Term::Id(s))
+			.map_predicate(|p| Term::Id(p))
+			.map_graph(|g| g.map(Term::Id))
+	}
+
+	/// Borrows the quad as a gRDF quad.
+	pub fn as_grdf(&self) -> GrdfQuad<Id<&I, &B>, &L> {
+		Quad(
+			Term::Id(self.0.as_ref()),
+			Term::Id(self.1.as_ref()),
+			self.2.as_ref().map_id(Id::as_ref),
+			self.3.as_ref().map(|g| Term::Id(g.as_ref())),
+		)
+	}
 }
 
 /// gRDF triple.
@@ -18,9 +51,70 @@ impl<I, B, L> Quad<Id<I, B>, I, Term<Id<I, B>, L>, Id<I, B>> {
 /// A triple where each component is a [`Term`].
 pub type GrdfTriple<I, L> = Triple<Term<I, L>>;
 
+/// RDF triple, whose components can be lifted into a gRDF triple.
+///
+/// Works with any IRI, blank node identifier and literal type, including
+/// vocabulary-indexed ones.
 impl<I, B, L> Triple<Id<I, B>, I, Term<Id<I, B>, L>> {
 	pub fn into_grdf(self) -> GrdfTriple<Id<I, B>, L> {
 		self.map_subject(|s| Term::Id(s))
 			.map_predicate(|p| Term::Id(Id::Iri(p)))
 	}
+
+	/// Borrows the triple as a gRDF triple.
+	pub fn as_grdf(&self) -> GrdfTriple<Id<&I, &B>, &L> {
+		Triple(
+			Term::Id(self.0.as_ref()),
+			Term::Id(Id::Iri(&self.1)),
+			self.2.as_ref().map_id(Id::as_ref),
+		)
+	}
+}
+
+/// Generalized RDF triple, where the predicate may be a blank node
+/// identifier.
+impl<I, B, L> Triple<Id<I, B>, Id<I, B>, Term<Id<I, B>, L>> {
+	pub fn into_grdf(self) -> GrdfTriple<Id<I, B>, L> {
+		self.map_subject(|s| Term::Id(s))
+			.map_predicate(|p| Term::Id(p))
+	}
+
+	/// Borrows the triple as a gRDF triple.
+	pub fn as_grdf(&self) -> GrdfTriple<Id<&I, &B>, &L> {
+		Triple(
+			Term::Id(self.0.as_ref()),
+			Term::Id(self.1.as_ref()),
+			self.2.as_ref().map_id(Id::as_ref),
+		)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::vocabulary::{BlankIdIndex, IriIndex, LiteralIndex};
+
+	type IndexedId = Id<IriIndex, BlankIdIndex>;
+
+	type IndexedTerm = Term<IndexedId, LiteralIndex>;
+
+	#[test]
+	fn indexed_into_grdf() {
+		let s: IndexedId = Id::Blank(BlankIdIndex::from(0));
+		let p = IriIndex::from(1);
+		let o: IndexedTerm = Term::Literal(LiteralIndex::from(2));
+
+		let triple = Triple(s, p, o);
+		assert_eq!(triple.as_grdf().1.as_id(), Some(&Id::Iri(&p)));
+
+		let expected: GrdfTriple<IndexedId, LiteralIndex> =
+			Triple(Term::Id(s), Term::Id(Id::Iri(p)), o);
+		assert_eq!(triple.into_grdf(), expected);
+
+		let generalized: Quad<_, _, _, IndexedId> = Quad(s, s, o, None);
+		assert_eq!(generalized.as_grdf().1.as_id(), Some(&s.as_ref()));
+
+		let expected: GrdfQuad<IndexedId, LiteralIndex> = Quad(Term::Id(s), Term::Id(s), o, None);
+		assert_eq!(generalized.into_grdf(), expected)
+	}
 }
@@ -0,0 +1,8 @@
+//! Interoperability with other RDF crates.
+//!
+//! Each module is enabled by the feature of the same name.
+#[cfg(feature = "oxrdf")]
+pub mod oxrdf;
+
+#[cfg(feature = "sophia")]
+pub mod sophia;
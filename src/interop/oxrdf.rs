@@ -0,0 +1,221 @@
+//! Conversions between this crate's types and the [`oxrdf`] crate's types.
+//!
+//! Converting into `oxrdf` types is infallible for node identifiers. Other
+//! conversions may fail, either because a lexical value is rejected by the
+//! target crate, or because the value has no counterpart in the target crate
+//! (RDF-star triple terms, directional language strings).
+use iref::IriBuf;
+use langtag::LangTagBuf;
+
+use crate::{BlankIdBuf, Id, LexicalQuad, Literal, LiteralType, Quad, Term};
+
+/// Error returned when converting from or into `oxrdf` types.
+#[derive(Debug, thiserror::Error)]
+pub enum OxrdfConversionError {
+	#[error("invalid IRI `{0}`")]
+	Iri(String),
+
+	#[error("invalid blank node identifier `{0}`")]
+	BlankId(String),
+
+	#[error("invalid language tag `{0}`")]
+	LanguageTag(String),
+
+	#[error("unsupported RDF-star triple term")]
+	TripleTerm,
+
+	#[error("unsupported directional language string")]
+	Direction,
+}
+
+fn iri_from_oxrdf(node: oxrdf::NamedNode) -> Result<IriBuf, OxrdfConversionError> {
+	IriBuf::new(node.as_str().to_owned()).map_err(|_| OxrdfConversionError::Iri(node.into_string()))
+}
+
+fn blank_id_from_oxrdf(node: oxrdf::BlankNode) -> Result<BlankIdBuf, OxrdfConversionError> {
+	BlankIdBuf::from_suffix(node.as_str())
+		.map_err(|_| OxrdfConversionError::BlankId(node.as_str().to_owned()))
+}
+
+fn iri_into_oxrdf(iri: IriBuf) -> oxrdf::NamedNode {
+	oxrdf::NamedNode::new_unchecked(iri.as_str())
+}
+
+fn blank_id_into_oxrdf(blank_id: BlankIdBuf) -> oxrdf::BlankNode {
+	oxrdf::BlankNode::new_unchecked(blank_id.suffix())
+}
+
+impl TryFrom<oxrdf::NamedNode> for Id {
+	type Error = OxrdfConversionError;
+
+	fn try_from(value: oxrdf::NamedNode) -> Result<Self, Self::Error> {
+		iri_from_oxrdf(value).map(Id::Iri)
+	}
+}
+
+impl TryFrom<oxrdf::BlankNode> for Id {
+	type Error = OxrdfConversionError;
+
+	fn try_from(value: oxrdf::BlankNode) -> Result<Self, Self::Error> {
+		blank_id_from_oxrdf(value).map(Id::Blank)
+	}
+}
+
+impl TryFrom<oxrdf::Subject> for Id {
+	type Error = OxrdfConversionError;
+
+	#[allow(unreachable_patterns)]
+	fn try_from(value: oxrdf::Subject) -> Result<Self, Self::Error> {
+		match value {
+			oxrdf::Subject::NamedNode(node) => node.try_into(),
+			oxrdf::Subject::BlankNode(node) => node.try_into(),
+			_ => Err(OxrdfConversionError::TripleTerm),
+		}
+	}
+}
+
+impl TryFrom<oxrdf::Literal> for Literal {
+	type Error = OxrdfConversionError;
+
+	fn try_from(value: oxrdf::Literal) -> Result<Self, Self::Error> {
+		let type_ = match value.language() {
+			Some(tag) => LiteralType::LangString(
+				LangTagBuf::new(tag.to_owned())
+					.map_err(|_| OxrdfConversionError::LanguageTag(tag.to_owned()))?,
+			),
+			None => LiteralType::Any(iri_from_oxrdf(value.datatype().into_owned())?),
+		};
+
+		Ok(Literal::new(value.value().to_owned(), type_))
+	}
+}
+
+impl TryFrom<oxrdf::Term> for Term {
+	type Error = OxrdfConversionError;
+
+	#[allow(unreachable_patterns)]
+	fn try_from(value: oxrdf::Term) -> Result<Self, Self::Error> {
+		match value {
+			oxrdf::Term::NamedNode(node) => node.try_into().map(Term::Id),
+			oxrdf::Term::BlankNode(node) => node.try_into().map(Term::Id),
+			oxrdf::Term::Literal(literal) => literal.try_into().map(Term::Literal),
+			_ => Err(OxrdfConversionError::TripleTerm),
+		}
+	}
+}
+
+impl TryFrom<oxrdf::Quad> for LexicalQuad {
+	type Error = OxrdfConversionError;
+
+	fn try_from(value: oxrdf::Quad) -> Result<Self, Self::Error> {
+		let graph = match value.graph_name {
+			oxrdf::GraphName::NamedNode(node) => Some(Id::try_from(node)?),
+			oxrdf::GraphName::BlankNode(node) => Some(Id::try_from(node)?),
+			oxrdf::GraphName::DefaultGraph => None,
+		};
+
+		Ok(Quad(
+			Id::try_from(value.subject)?,
+			iri_from_oxrdf(value.predicate)?,
+			Term::try_from(value.object)?,
+			graph,
+		))
+	}
+}
+
+impl From<Id> for oxrdf::Subject {
+	fn from(value: Id) -> Self {
+		match value {
+			Id::Iri(iri) => Self::NamedNode(iri_into_oxrdf(iri)),
+			Id::Blank(blank_id) => Self::BlankNode(blank_id_into_oxrdf(blank_id)),
+		}
+	}
+}
+
+impl From<Id> for oxrdf::GraphName {
+	fn from(value: Id) -> Self {
+		match value {
+			Id::Iri(iri) => Self::NamedNode(iri_into_oxrdf(iri)),
+			Id::Blank(blank_id) => Self::BlankNode(blank_id_into_oxrdf(blank_id)),
+		}
+	}
+}
+
+impl From<Id> for oxrdf::Term {
+	fn from(value: Id) -> Self {
+		match value {
+			Id::Iri(iri) => Self::NamedNode(iri_into_oxrdf(iri)),
+			Id::Blank(blank_id) => Self::BlankNode(blank_id_into_oxrdf(blank_id)),
+		}
+	}
+}
+
+impl TryFrom<Literal> for oxrdf::Literal {
+	type Error = OxrdfConversionError;
+
+	fn try_from(value: Literal) -> Result<Self, Self::Error> {
+		match value.type_ {
+			LiteralType::Any(ty) => Ok(Self::new_typed_literal(value.value, iri_into_oxrdf(ty))),
+			LiteralType::LangString(tag) => Ok(Self::new_language_tagged_literal_unchecked(
+				value.value,
+				tag.as_str(),
+			)),
+			LiteralType::DirLangString(_, _) => Err(OxrdfConversionError::Direction),
+		}
+	}
+}
+
+impl TryFrom<Term> for oxrdf::Term {
+	type Error = OxrdfConversionError;
+
+	fn try_from(value: Term) -> Result<Self, Self::Error> {
+		match value {
+			Term::Id(id) => Ok(id.into()),
+			Term::Literal(literal) => literal.try_into().map(Self::Literal),
+		}
+	}
+}
+
+impl TryFrom<LexicalQuad> for oxrdf::Quad {
+	type Error = OxrdfConversionError;
+
+	fn try_from(Quad(s, p, o, g): LexicalQuad) -> Result<Self, Self::Error> {
+		Ok(Self::new(
+			s,
+			iri_into_oxrdf(p),
+			oxrdf::Term::try_from(o)?,
+			g.map(oxrdf::GraphName::from)
+				.unwrap_or(oxrdf::GraphName::DefaultGraph),
+		))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn round_trip() {
+		for input in [
+			"_:a <http://example.org/#p> <http://example.org/#o> .",
+			"<http://example.org/#s> <http://example.org/#p> _:b <http://example.org/#g> .",
+			"_:a <http://example.org/#p> \"v\"@en _:g .",
+			"_:a <http://example.org/#p> \"1\"^^<http://www.w3.org/2001/XMLSchema#integer> .",
+		] {
+			let quad: LexicalQuad = input.parse().unwrap();
+			let converted = oxrdf::Quad::try_from(quad.clone()).unwrap();
+			assert_eq!(LexicalQuad::try_from(converted).unwrap(), quad)
+		}
+	}
+
+	#[test]
+	fn direction() {
+		let quad: LexicalQuad = "_:a <http://example.org/#p> \"v\"@en--ltr ."
+			.parse()
+			.unwrap();
+		assert!(matches!(
+			oxrdf::Quad::try_from(quad),
+			Err(OxrdfConversionError::Direction)
+		))
+	}
+}
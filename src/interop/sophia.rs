@@ -0,0 +1,203 @@
+//! Conversions between this crate's types and the [`sophia_api`] crate's
+//! terms.
+//!
+//! Lexical terms ([`Term`] and [`Id`]) implement the
+//! [`sophia_api::term::Term`] trait, so that they can be directly consumed by
+//! sophia's algorithms. Any sophia term can be turned back into a [`Term`]
+//! using [`Term::from_sophia`].
+//!
+//! Sophia has no notion of base direction: directional language strings are
+//! seen as plain language strings, and their direction is lost.
+use iref::IriBuf;
+use langtag::LangTagBuf;
+use sophia_api::{
+	term::{BnodeId, IriRef, LanguageTag, TermKind},
+	MownStr,
+};
+
+use crate::{BlankIdBuf, Id, Literal, LiteralType, Term, RDF_LANG_STRING};
+
+/// Error returned when converting a sophia term.
+#[derive(Debug, thiserror::Error)]
+pub enum SophiaConversionError {
+	#[error("invalid IRI `{0}`")]
+	Iri(String),
+
+	#[error("invalid blank node identifier `{0}`")]
+	BlankId(String),
+
+	#[error("invalid language tag `{0}`")]
+	LanguageTag(String),
+
+	#[error("unsupported term kind: {0:?}")]
+	Unsupported(TermKind),
+}
+
+fn iri_from_sophia(iri: String) -> Result<IriBuf, SophiaConversionError> {
+	IriBuf::new(iri.clone()).map_err(|_| SophiaConversionError::Iri(iri))
+}
+
+impl sophia_api::term::Term for Id {
+	type BorrowTerm<'x>
+		= &'x Self
+	where
+		Self: 'x;
+
+	fn kind(&self) -> TermKind {
+		match self {
+			Self::Iri(_) => TermKind::Iri,
+			Self::Blank(_) => TermKind::BlankNode,
+		}
+	}
+
+	fn iri(&self) -> Option<IriRef<MownStr>> {
+		self.as_iri()
+			.map(|iri| IriRef::new_unchecked(MownStr::from(iri.as_str())))
+	}
+
+	fn bnode_id(&self) -> Option<BnodeId<MownStr>> {
+		self.as_blank()
+			.map(|b| BnodeId::new_unchecked(MownStr::from(b.suffix())))
+	}
+
+	fn borrow_term(&self) -> Self::BorrowTerm<'_> {
+		self
+	}
+}
+
+impl sophia_api::term::Term for Term {
+	type BorrowTerm<'x>
+		= &'x Self
+	where
+		Self: 'x;
+
+	fn kind(&self) -> TermKind {
+		match self {
+			Self::Id(id) => sophia_api::term::Term::kind(id),
+			Self::Literal(_) => TermKind::Literal,
+		}
+	}
+
+	fn iri(&self) -> Option<IriRef<MownStr>> {
+		self.as_id().and_then(sophia_api::term::Term::iri)
+	}
+
+	fn bnode_id(&self) -> Option<BnodeId<MownStr>> {
+		self.as_id().and_then(sophia_api::term::Term::bnode_id)
+	}
+
+	fn lexical_form(&self) -> Option<MownStr> {
+		self.as_literal().map(|l| MownStr::from(l.value.as_str()))
+	}
+
+	/// Returns the datatype of the literal.
+	///
+	/// Language strings have the `rdf:langString` datatype. Sophia has no
+	/// notion of base direction, so directional language strings are also
+	/// reported as `rdf:langString` literals, and their direction is lost.
+	fn datatype(&self) -> Option<IriRef<MownStr>> {
+		self.as_literal().map(|l| {
+			let ty = match &l.type_ {
+				LiteralType::Any(ty) => ty.as_str(),
+				_ => RDF_LANG_STRING.as_str(),
+			};
+
+			IriRef::new_unchecked(MownStr::from(ty))
+		})
+	}
+
+	/// Returns the language tag of the literal.
+	///
+	/// The base direction of directional language strings is dropped.
+	fn language_tag(&self) -> Option<LanguageTag<MownStr>> {
+		match &self.as_literal()?.type_ {
+			LiteralType::Any(_) => None,
+			LiteralType::LangString(tag) | LiteralType::DirLangString(tag, _) => {
+				Some(LanguageTag::new_unchecked(MownStr::from(tag.as_str())))
+			}
+		}
+	}
+
+	fn borrow_term(&self) -> Self::BorrowTerm<'_> {
+		self
+	}
+}
+
+impl Term {
+	/// Converts any sophia term into a lexical term.
+	///
+	/// Fails on variables and RDF-star triple terms, which have no lexical
+	/// term counterpart, and on relative IRI references.
+	pub fn from_sophia<T: sophia_api::term::Term>(term: T) -> Result<Self, SophiaConversionError> {
+		match term.kind() {
+			TermKind::Iri => {
+				let iri = term.iri().unwrap().unwrap().to_string();
+				iri_from_sophia(iri).map(|iri| Self::Id(Id::Iri(iri)))
+			}
+			TermKind::BlankNode => {
+				let suffix = term.bnode_id().unwrap().unwrap();
+				BlankIdBuf::from_suffix(&suffix)
+					.map(|b| Self::Id(Id::Blank(b)))
+					.map_err(|_| SophiaConversionError::BlankId(suffix.to_string()))
+			}
+			TermKind::Literal => {
+				let value = term.lexical_form().unwrap().to_string();
+				let type_ = match term.language_tag() {
+					Some(tag) => {
+						let tag = tag.unwrap().to_string();
+						LiteralType::LangString(
+							LangTagBuf::new(tag.clone())
+								.map_err(|_| SophiaConversionError::LanguageTag(tag))?,
+						)
+					}
+					None => {
+						let ty = term.datatype().unwrap().unwrap().to_string();
+						LiteralType::Any(iri_from_sophia(ty)?)
+					}
+				};
+
+				Ok(Self::Literal(Literal::new(value, type_)))
+			}
+			kind => Err(SophiaConversionError::Unsupported(kind)),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{Direction, XSD_INTEGER};
+	use sophia_api::term::SimpleTerm;
+	use static_iref::iri;
+
+	#[test]
+	fn round_trip() {
+		let terms: [Term; 4] = [
+			Term::Id(Id::Iri(iri!("http://example.org/#a").to_owned())),
+			Term::Id(Id::Blank(BlankIdBuf::new("_:b0").unwrap())),
+			Term::Literal(Literal::new_lang(
+				"v",
+				LangTagBuf::new("en".to_owned()).unwrap(),
+			)),
+			Term::Literal(Literal::new_typed("1", XSD_INTEGER.to_owned())),
+		];
+
+		for term in terms {
+			let simple: SimpleTerm = sophia_api::term::Term::into_term(term.clone());
+			assert!(sophia_api::term::Term::eq(&term, &simple));
+			assert_eq!(Term::from_sophia(&simple).unwrap(), term)
+		}
+
+		// The direction is lost.
+		let en = LangTagBuf::new("en".to_owned()).unwrap();
+		let term = Term::Literal(Literal::new(
+			"v".to_owned(),
+			LiteralType::DirLangString(en.clone(), Direction::Rtl),
+		));
+		let simple: SimpleTerm = sophia_api::term::Term::into_term(term);
+		assert_eq!(
+			Term::from_sophia(&simple).unwrap(),
+			Term::Literal(Literal::new_lang("v", en))
+		)
+	}
+}
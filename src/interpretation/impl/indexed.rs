@@ -1,4 +1,8 @@
+use std::borrow::Borrow;
 use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+
+use iref::IriBuf;
 
 use crate::interpretation::{
 	BlankIdInterpretation, BlankIdInterpretationMut, IriInterpretation, IriInterpretationMut,
@@ -7,7 +11,7 @@ use crate::interpretation::{
 	ReverseLiteralInterpretation, ReverseLiteralInterpretationMut, TraversableInterpretation,
 };
 use crate::vocabulary::{BlankIdIndex, IriIndex, LiteralIndex};
-use crate::{Interpretation, InterpretationMut};
+use crate::{BlankIdBuf, Interpretation, InterpretationMut, Literal};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct ResourceIndex(usize);
@@ -24,15 +28,26 @@ impl From<ResourceIndex> for usize {
 	}
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
-struct Resource {
+#[derive(Debug, Clone)]
+struct Resource<I, B, L> {
 	index: ResourceIndex,
-	iris: HashSet<IriIndex>,
-	blank_ids: HashSet<BlankIdIndex>,
-	literals: HashSet<LiteralIndex>,
+	iris: HashSet<I>,
+	blank_ids: HashSet<B>,
+	literals: HashSet<L>,
+}
+
+impl<I: Eq + Hash, B: Eq + Hash, L: Eq + Hash> PartialEq for Resource<I, B, L> {
+	fn eq(&self, other: &Self) -> bool {
+		self.index == other.index
+			&& self.iris == other.iris
+			&& self.blank_ids == other.blank_ids
+			&& self.literals == other.literals
+	}
 }
 
-impl Resource {
+impl<I: Eq + Hash, B: Eq + Hash, L: Eq + Hash> Eq for Resource<I, B, L> {}
+
+impl<I, B, L> Resource<I, B, L> {
 	fn new(index: ResourceIndex) -> Self {
 		Self {
 			index,
@@ -43,10 +58,24 @@ impl Resource {
 	}
 }
 
-#[derive(Debug, Default, Clone, PartialEq, Eq)]
-struct Resources(Vec<Resource>);
+#[derive(Debug, Clone)]
+struct Resources<I, B, L>(Vec<Resource<I, B, L>>);
+
+impl<I: Eq + Hash, B: Eq + Hash, L: Eq + Hash> PartialEq for Resources<I, B, L> {
+	fn eq(&self, other: &Self) -> bool {
+		self.0 == other.0
+	}
+}
+
+impl<I: Eq + Hash, B: Eq + Hash, L: Eq + Hash> Eq for Resources<I, B, L> {}
+
+impl<I, B, L> Default for Resources<I, B, L> {
+	fn default() -> Self {
+		Self(Vec::new())
+	}
+}
 
-impl Resources {
+impl<I, B, L> Resources<I, B, L> {
 	fn len(&self) -> usize {
 		self.0.len()
 	}
@@ -55,15 +84,15 @@ impl Resources {
 		self.0.is_empty()
 	}
 
-	fn get(&self, i: ResourceIndex) -> Option<&Resource> {
+	fn get(&self, i: ResourceIndex) -> Option<&Resource<I, B, L>> {
 		self.0.get(i.0)
 	}
 
-	fn get_mut(&mut self, i: ResourceIndex) -> Option<&mut Resource> {
+	fn get_mut(&mut self, i: ResourceIndex) -> Option<&mut Resource<I, B, L>> {
 		self.0.get_mut(i.0)
 	}
 
-	fn insert(&mut self) -> (ResourceIndex, &mut Resource) {
+	fn insert(&mut self) -> (ResourceIndex, &mut Resource<I, B, L>) {
 		let i = ResourceIndex(self.0.len());
 		self.0.push(Resource::new(i));
 		let r = self.0.last_mut().unwrap();
@@ -71,15 +100,47 @@ impl Resources {
 	}
 }
 
-#[derive(Debug, Default, Clone, PartialEq, Eq)]
-pub struct Indexed {
-	resources: Resources,
-	by_iri: HashMap<IriIndex, ResourceIndex>,
-	by_blank_id: HashMap<BlankIdIndex, ResourceIndex>,
-	by_literal: HashMap<LiteralIndex, ResourceIndex>,
+/// Indexed interpretation.
+///
+/// Assigns a [`ResourceIndex`] to each distinct IRI, blank node identifier
+/// and literal it interprets. By default it interprets the indexes of an
+/// [`IndexVocabulary`](crate::vocabulary::IndexVocabulary). See
+/// [`IndexedInterpretation`] to interpret lexical terms directly.
+#[derive(Debug, Clone)]
+pub struct Indexed<I = IriIndex, B = BlankIdIndex, L = LiteralIndex> {
+	resources: Resources<I, B, L>,
+	by_iri: HashMap<I, ResourceIndex>,
+	by_blank_id: HashMap<B, ResourceIndex>,
+	by_literal: HashMap<L, ResourceIndex>,
+}
+
+/// Indexed interpretation of lexical IRIs, blank node identifiers and
+/// literals, without vocabulary.
+pub type IndexedInterpretation = Indexed<IriBuf, BlankIdBuf, Literal>;
+
+impl<I: Eq + Hash, B: Eq + Hash, L: Eq + Hash> PartialEq for Indexed<I, B, L> {
+	fn eq(&self, other: &Self) -> bool {
+		self.resources == other.resources
+			&& self.by_iri == other.by_iri
+			&& self.by_blank_id == other.by_blank_id
+			&& self.by_literal == other.by_literal
+	}
+}
+
+impl<I: Eq + Hash, B: Eq + Hash, L: Eq + Hash> Eq for Indexed<I, B, L> {}
+
+impl<I, B, L> Default for Indexed<I, B, L> {
+	fn default() -> Self {
+		Self {
+			resources: Resources::default(),
+			by_iri: HashMap::new(),
+			by_blank_id: HashMap::new(),
+			by_literal: HashMap::new(),
+		}
+	}
 }
 
-impl Indexed {
+impl<I, B, L> Indexed<I, B, L> {
 	pub fn new() -> Self {
 		Self::default()
 	}
@@ -93,27 +154,32 @@ impl Indexed {
 	}
 }
 
-impl Interpretation for Indexed {
+impl<I, B, L> Interpretation for Indexed<I, B, L> {
 	type Resource = ResourceIndex;
 }
 
-impl TraversableInterpretation for Indexed {
-	type Resources<'a> = ResourceIndexIter<'a>;
+impl<I, B, L> TraversableInterpretation for Indexed<I, B, L> {
+	type Resources<'a>
+		= ResourceIndexIter<'a, I, B, L>
+	where
+		Self: 'a;
 
 	fn resources(&self) -> Self::Resources<'_> {
 		ResourceIndexIter(self.resources.0.iter())
 	}
 }
 
-impl<V> InterpretationMut<V> for Indexed {
+impl<I, B, L, V> InterpretationMut<V> for Indexed<I, B, L> {
 	fn new_resource(&mut self, _vocabulary: &mut V) -> Self::Resource {
 		self.resources.insert().0
 	}
 }
 
-pub struct ResourceIndexIter<'a>(std::slice::Iter<'a, Resource>);
+pub struct ResourceIndexIter<'a, I = IriIndex, B = BlankIdIndex, L = LiteralIndex>(
+	std::slice::Iter<'a, Resource<I, B, L>>,
+);
 
-impl<'a> Iterator for ResourceIndexIter<'a> {
+impl<'a, I, B, L> Iterator for ResourceIndexIter<'a, I, B, L> {
 	type Item = &'a ResourceIndex;
 
 	fn next(&mut self) -> Option<Self::Item> {
@@ -121,15 +187,17 @@ impl<'a> Iterator for ResourceIndexIter<'a> {
 	}
 }
 
-impl IriInterpretation<IriIndex> for Indexed {
-	fn iri_interpretation(&self, iri: &IriIndex) -> Option<Self::Resource> {
+impl<I: Borrow<Q> + Eq + Hash, B, L, Q: ?Sized + Eq + Hash> IriInterpretation<Q>
+	for Indexed<I, B, L>
+{
+	fn iri_interpretation(&self, iri: &Q) -> Option<Self::Resource> {
 		self.by_iri.get(iri).copied()
 	}
 }
 
-impl IriInterpretationMut<IriIndex> for Indexed {
-	fn interpret_iri(&mut self, iri: IriIndex) -> Self::Resource {
-		*self.by_iri.entry(iri).or_insert_with(|| {
+impl<I: Clone + Eq + Hash, B, L> IriInterpretationMut<I> for Indexed<I, B, L> {
+	fn interpret_iri(&mut self, iri: I) -> Self::Resource {
+		*self.by_iri.entry(iri.clone()).or_insert_with(|| {
 			let (i, r) = self.resources.insert();
 			r.iris.insert(iri);
 			i
@@ -137,15 +205,17 @@ impl IriInterpretationMut<IriIndex> for Indexed {
 	}
 }
 
-impl BlankIdInterpretation<BlankIdIndex> for Indexed {
-	fn blank_id_interpretation(&self, blank_id: &BlankIdIndex) -> Option<Self::Resource> {
+impl<I, B: Borrow<Q> + Eq + Hash, L, Q: ?Sized + Eq + Hash> BlankIdInterpretation<Q>
+	for Indexed<I, B, L>
+{
+	fn blank_id_interpretation(&self, blank_id: &Q) -> Option<Self::Resource> {
 		self.by_blank_id.get(blank_id).copied()
 	}
 }
 
-impl BlankIdInterpretationMut<BlankIdIndex> for Indexed {
-	fn interpret_blank_id(&mut self, blank_id: BlankIdIndex) -> Self::Resource {
-		*self.by_blank_id.entry(blank_id).or_insert_with(|| {
+impl<I, B: Clone + Eq + Hash, L> BlankIdInterpretationMut<B> for Indexed<I, B, L> {
+	fn interpret_blank_id(&mut self, blank_id: B) -> Self::Resource {
+		*self.by_blank_id.entry(blank_id.clone()).or_insert_with(|| {
 			let (i, r) = self.resources.insert();
 			r.blank_ids.insert(blank_id);
 			i
@@ -153,15 +223,15 @@ impl BlankIdInterpretationMut<BlankIdIndex> for Indexed {
 	}
 }
 
-impl LiteralInterpretation<LiteralIndex> for Indexed {
-	fn literal_interpretation(&self, literal: &LiteralIndex) -> Option<Self::Resource> {
+impl<I, B, L: Eq + Hash> LiteralInterpretation<L> for Indexed<I, B, L> {
+	fn literal_interpretation(&self, literal: &L) -> Option<Self::Resource> {
 		self.by_literal.get(literal).copied()
 	}
 }
 
-impl LiteralInterpretationMut<LiteralIndex> for Indexed {
-	fn interpret_literal(&mut self, literal: LiteralIndex) -> Self::Resource {
-		*self.by_literal.entry(literal).or_insert_with(|| {
+impl<I, B, L: Clone + Eq + Hash> LiteralInterpretationMut<L> for Indexed<I, B, L> {
+	fn interpret_literal(&mut self, literal: L) -> Self::Resource {
+		*self.by_literal.entry(literal.clone()).or_insert_with(|| {
 			let (i, r) = self.resources.insert();
 			r.literals.insert(literal);
 			i
@@ -169,13 +239,15 @@ impl LiteralInterpretationMut<LiteralIndex> for Indexed {
 	}
 }
 
-impl ReverseIriInterpretation for Indexed {
-	type Iri = IriIndex;
+impl<I, B, L> ReverseIriInterpretation for Indexed<I, B, L> {
+	type Iri = I;
 
-	type Iris<'a> =
-		std::iter::Flatten<std::option::IntoIter<std::collections::hash_set::Iter<'a, IriIndex>>>;
+	type Iris<'a>
+		= std::iter::Flatten<std::option::IntoIter<std::collections::hash_set::Iter<'a, I>>>
+	where
+		Self: 'a;
 
-	fn iris_of(&self, id: &Self::Resource) -> Self::Iris<'_> {
+	fn iris_of<'a>(&'a self, id: &'a Self::Resource) -> Self::Iris<'a> {
 		self.resources
 			.get(*id)
 			.map(|r| r.iris.iter())
@@ -184,14 +256,15 @@ impl ReverseIriInterpretation for Indexed {
 	}
 }
 
-impl ReverseBlankIdInterpretation for Indexed {
-	type BlankId = BlankIdIndex;
+impl<I, B, L> ReverseBlankIdInterpretation for Indexed<I, B, L> {
+	type BlankId = B;
 
-	type BlankIds<'a> = std::iter::Flatten<
-		std::option::IntoIter<std::collections::hash_set::Iter<'a, BlankIdIndex>>,
-	>;
+	type BlankIds<'a>
+		= std::iter::Flatten<std::option::IntoIter<std::collections::hash_set::Iter<'a, B>>>
+	where
+		Self: 'a;
 
-	fn blank_ids_of(&self, id: &Self::Resource) -> Self::BlankIds<'_> {
+	fn blank_ids_of<'a>(&'a self, id: &'a Self::Resource) -> Self::BlankIds<'a> {
 		self.resources
 			.get(*id)
 			.map(|r| r.blank_ids.iter())
@@ -200,14 +273,15 @@ impl ReverseBlankIdInterpretation for Indexed {
 	}
 }
 
-impl ReverseLiteralInterpretation for Indexed {
-	type Literal = LiteralIndex;
+impl<I, B, L> ReverseLiteralInterpretation for Indexed<I, B, L> {
+	type Literal = L;
 
-	type Literals<'a> = std::iter::Flatten<
-		std::option::IntoIter<std::collections::hash_set::Iter<'a, LiteralIndex>>,
-	>;
+	type Literals<'a>
+		= std::iter::Flatten<std::option::IntoIter<std::collections::hash_set::Iter<'a, L>>>
+	where
+		Self: 'a;
 
-	fn literals_of(&self, id: &Self::Resource) -> Self::Literals<'_> {
+	fn literals_of<'a>(&'a self, id: &'a Self::Resource) -> Self::Literals<'a> {
 		self.resources
 			.get(*id)
 			.map(|r| r.literals.iter())
@@ -216,21 +290,21 @@ impl ReverseLiteralInterpretation for Indexed {
 	}
 }
 
-impl ReverseIriInterpretationMut for Indexed {
+impl<I: Eq + Hash, B, L> ReverseIriInterpretationMut for Indexed<I, B, L> {
 	fn assign_iri(&mut self, resource: &Self::Resource, iri: Self::Iri) -> bool {
 		let r = self.resources.get_mut(*resource).unwrap();
 		r.iris.insert(iri)
 	}
 }
 
-impl ReverseBlankIdInterpretationMut for Indexed {
+impl<I, B: Eq + Hash, L> ReverseBlankIdInterpretationMut for Indexed<I, B, L> {
 	fn assign_blank_id(&mut self, resource: &Self::Resource, blank_id: Self::BlankId) -> bool {
 		let r = self.resources.get_mut(*resource).unwrap();
 		r.blank_ids.insert(blank_id)
 	}
 }
 
-impl ReverseLiteralInterpretationMut for Indexed {
+impl<I, B, L: Eq + Hash> ReverseLiteralInterpretationMut for Indexed<I, B, L> {
 	fn assign_literal(&mut self, resource: &Self::Resource, literal: Self::Literal) -> bool {
 		self.resources
 			.get_mut(*resource)
@@ -239,3 +313,81 @@ impl ReverseLiteralInterpretationMut for Indexed {
 			.insert(literal)
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::interpretation::ReverseTermInterpretation;
+	use crate::{BlankId, Id, LexicalTermRef, LiteralType, Term, XSD_STRING};
+	use static_iref::iri;
+
+	#[test]
+	fn interpret_lexical_terms() {
+		let mut interpretation = IndexedInterpretation::new();
+
+		let a = interpretation.interpret_iri(iri!("http://example.org/#a").to_owned());
+		let b = interpretation.interpret_blank_id(BlankIdBuf::from_suffix("b").unwrap());
+		let c = interpretation.interpret_literal(Literal::new(
+			"c".to_owned(),
+			LiteralType::Any(XSD_STRING.to_owned()),
+		));
+
+		assert_ne!(a, b);
+		assert_ne!(b, c);
+		assert_eq!(interpretation.len(), 3);
+		assert_eq!(
+			interpretation.interpret_iri(iri!("http://example.org/#a").to_owned()),
+			a
+		);
+		assert_eq!(interpretation.len(), 3);
+		assert_eq!(
+			interpretation.iri_interpretation(iri!("http://example.org/#a")),
+			Some(a)
+		);
+
+		let other = iri!("http://example.org/#other").to_owned();
+		assert!(interpretation.assign_iri(&a, other.clone()));
+		let mut iris: Vec<_> = interpretation.iris_of(&a).cloned().collect();
+		iris.sort();
+		assert_eq!(iris, [iri!("http://example.org/#a").to_owned(), other]);
+		assert_eq!(interpretation.blank_ids_of(&a).count(), 0);
+		assert_eq!(interpretation.blank_ids_of(&b).count(), 1);
+		assert_eq!(interpretation.literals_of(&c).count(), 1)
+	}
+
+	#[test]
+	fn lexical_term_of() {
+		let mut interpretation = IndexedInterpretation::new();
+
+		let a = interpretation.interpret_blank_id(BlankIdBuf::from_suffix("a").unwrap());
+		assert_eq!(
+			interpretation.lexical_term_of(&a),
+			Some(Term::Id(Id::Blank(BlankId::new("_:a").unwrap())))
+		);
+
+		interpretation.assign_iri(&a, iri!("http://example.org/#a").to_owned());
+		assert_eq!(
+			interpretation.lexical_term_of(&a),
+			Some(Term::Id(Id::Iri(iri!("http://example.org/#a"))))
+		);
+
+		let b = interpretation.new_resource(&mut ());
+		assert_eq!(interpretation.lexical_term_of(&b), None)
+	}
+
+	#[test]
+	fn lexical_terms_of() {
+		let mut interpretation = IndexedInterpretation::new();
+
+		let a = interpretation.interpret_blank_id(BlankIdBuf::from_suffix("a").unwrap());
+		interpretation.assign_iri(&a, iri!("http://example.org/#a").to_owned());
+		let expected: Vec<LexicalTermRef> = vec![
+			Term::Id(Id::Iri(iri!("http://example.org/#a"))),
+			Term::Id(Id::Blank(BlankId::new("_:a").unwrap())),
+		];
+		assert_eq!(
+			interpretation.lexical_terms_of(&a).collect::<Vec<_>>(),
+			expected
+		)
+	}
+}
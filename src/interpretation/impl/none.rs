@@ -31,6 +31,21 @@ impl IriInterpretation<Iri> for () {
 	}
 }
 
+impl<'a> IriInterpretationMut<&'a Iri> for () {
+	fn interpret_iri(&mut self, iri: &'a Iri) -> Self::Resource {
+		Term::Id(Id::Iri(iri.to_owned()))
+	}
+}
+
+/// Interprets the given string as an IRI, if it is a valid IRI.
+impl IriInterpretation<str> for () {
+	fn iri_interpretation(&self, iri: &str) -> Option<Self::Resource> {
+		Iri::new(iri)
+			.ok()
+			.map(|iri| Term::Id(Id::Iri(iri.to_owned())))
+	}
+}
+
 impl ReverseIriInterpretation for () {
 	type Iri = IriBuf;
 
@@ -62,6 +77,12 @@ impl BlankIdInterpretation<BlankId> for () {
 	}
 }
 
+impl<'a> BlankIdInterpretationMut<&'a BlankId> for () {
+	fn interpret_blank_id(&mut self, blank_id: &'a BlankId) -> Self::Resource {
+		Term::Id(Id::Blank(blank_id.to_owned()))
+	}
+}
+
 impl ReverseBlankIdInterpretation for () {
 	type BlankId = BlankIdBuf;
 
@@ -87,6 +108,18 @@ impl LiteralInterpretationMut<Literal> for () {
 	}
 }
 
+impl<'a> LiteralInterpretation<&'a Literal> for () {
+	fn literal_interpretation(&self, literal: &&'a Literal) -> Option<Self::Resource> {
+		Some(Term::Literal((*literal).clone()))
+	}
+}
+
+impl<'a> LiteralInterpretationMut<&'a Literal> for () {
+	fn interpret_literal(&mut self, literal: &'a Literal) -> Self::Resource {
+		Term::Literal(literal.clone())
+	}
+}
+
 impl ReverseLiteralInterpretation for () {
 	type Literal = Literal;
 
@@ -99,3 +132,26 @@ impl ReverseLiteralInterpretation for () {
 		}
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use static_iref::iri;
+
+	#[test]
+	fn borrowed_inputs() {
+		let iri = iri!("http://example.org/#a");
+		let expected: Term = Term::Id(Id::Iri(iri.to_owned()));
+		assert_eq!(
+			().iri_interpretation("http://example.org/#a"),
+			Some(expected.clone())
+		);
+		assert_eq!(().iri_interpretation("not an IRI"), None);
+		assert_eq!(().interpret_iri(iri), expected);
+
+		let literal = Literal::new_string("a");
+		let expected: Term = Term::Literal(literal.clone());
+		assert_eq!(().interpret_literal(&literal), expected);
+		assert_eq!(().literal_interpretation(&&literal), Some(expected))
+	}
+}
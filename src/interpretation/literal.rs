@@ -78,6 +78,9 @@ pub trait LiteralInterpretationMut<L = Literal>: Interpretation {
 		let type_ = match type_ {
 			literal::LiteralType::Any(ty) => literal::LiteralType::Any(vocabulary.insert_owned(ty)),
 			literal::LiteralType::LangString(tag) => literal::LiteralType::LangString(tag),
+			literal::LiteralType::DirLangString(tag, direction) => {
+				literal::LiteralType::DirLangString(tag, direction)
+			}
 		};
 
 		self.interpret_literal(vocabulary.insert_owned_literal(Literal::new(value, type_)))
@@ -1,8 +1,9 @@
-use iref::Iri;
+use iref::{Iri, IriBuf};
 
 use crate::{
 	vocabulary::{BlankIdVocabulary, IriVocabulary},
-	BlankId, Generator, Id, Literal, LiteralRef, Quad, Term, Vocabulary, VocabularyMut,
+	BlankId, BlankIdBuf, Generator, Id, LexicalTermRef, Literal, LiteralRef, Quad, Term,
+	Vocabulary, VocabularyMut,
 };
 
 use super::{
@@ -113,6 +114,30 @@ pub trait ReverseTermInterpretation:
 		self.term_of(id).is_some()
 	}
 
+	/// Returns a lexical term of the given resource, if any.
+	///
+	/// The first IRI of the resource is returned, otherwise its first blank
+	/// node identifier, otherwise its first literal.
+	fn lexical_term_of<'a>(&'a self, id: &'a Self::Resource) -> Option<LexicalTermRef<'a>>
+	where
+		Self: ReverseIriInterpretation<Iri = IriBuf>
+			+ ReverseBlankIdInterpretation<BlankId = BlankIdBuf>
+			+ ReverseLiteralInterpretation<Literal = Literal>,
+	{
+		self.lexical_terms_of(id).next()
+	}
+
+	/// Returns every lexical term of the given resource, in the same order as
+	/// [`Self::terms_of`]: IRIs, then blank node identifiers, then literals.
+	fn lexical_terms_of<'a>(&'a self, id: &'a Self::Resource) -> LexicalTermsOf<'a, Self>
+	where
+		Self: ReverseIriInterpretation<Iri = IriBuf>
+			+ ReverseBlankIdInterpretation<BlankId = BlankIdBuf>
+			+ ReverseLiteralInterpretation<Literal = Literal>,
+	{
+		self.terms_of(id).map(as_lexical_term_ref as fn(_) -> _)
+	}
+
 	fn quads_of<'a>(
 		&'a self,
 		quad: Quad<&'a Self::Resource, &'a Self::Resource, &'a Self::Resource, &'a Self::Resource>,
@@ -140,6 +165,22 @@ pub trait ReverseTermInterpretation:
 	}
 }
 
+/// Iterator over the lexical terms of a resource.
+///
+/// Returned by [`ReverseTermInterpretation::lexical_terms_of`].
+pub type LexicalTermsOf<'a, I> =
+	std::iter::Map<TermsOf<'a, I>, fn(TermOf<'a, I>) -> LexicalTermRef<'a>>;
+
+fn as_lexical_term_ref<'a>(
+	term: Term<Id<&'a IriBuf, &'a BlankIdBuf>, &'a Literal>,
+) -> LexicalTermRef<'a> {
+	match term {
+		Term::Id(Id::Iri(iri)) => Term::Id(Id::Iri(iri.as_iri())),
+		Term::Id(Id::Blank(b)) => Term::Id(Id::Blank(b.as_blank_id_ref())),
+		Term::Literal(l) => Term::Literal(l),
+	}
+}
+
 pub struct QuadsOf<'a, I: ?Sized + ReverseTermInterpretation> {
 	s: IdsOf<'a, I>,
 	p: I::Iris<'a>,
@@ -28,6 +28,8 @@ mod display;
 mod grdf;
 mod literal;
 mod r#macro;
+mod parse;
+mod prefix;
 mod quad;
 mod schema;
 mod term;
@@ -37,17 +39,27 @@ pub use blankid::*;
 pub use display::*;
 pub use grdf::*;
 pub use literal::*;
+pub use prefix::*;
 pub use quad::*;
 pub use schema::*;
 pub use term::*;
 pub use triple::*;
 
+pub mod canonical;
+pub mod canonicalize;
+pub mod collection;
 pub mod dataset;
+pub mod datatype;
 pub mod generator;
+pub mod interop;
 pub mod interpretation;
 pub mod pattern;
+#[cfg(feature = "serde")]
+pub mod serde;
 pub mod utils;
+pub mod vocab;
 pub mod vocabulary;
+pub mod write;
 
 pub use dataset::Dataset;
 pub use generator::Generator;
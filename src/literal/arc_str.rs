@@ -0,0 +1,121 @@
+use std::borrow::Borrow;
+use std::fmt;
+use std::ops::Deref;
+use std::sync::Arc;
+
+use crate::RdfDisplay;
+
+/// Shared, reference-counted string.
+///
+/// Can be used as literal value type (`Literal<I, ArcStr>`) so that cloning
+/// a literal only increments a reference counter instead of copying its
+/// value.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ArcStr(Arc<str>);
+
+impl ArcStr {
+	pub fn as_str(&self) -> &str {
+		&self.0
+	}
+}
+
+impl Deref for ArcStr {
+	type Target = str;
+
+	fn deref(&self) -> &str {
+		&self.0
+	}
+}
+
+impl AsRef<str> for ArcStr {
+	fn as_ref(&self) -> &str {
+		&self.0
+	}
+}
+
+impl Borrow<str> for ArcStr {
+	fn borrow(&self) -> &str {
+		&self.0
+	}
+}
+
+impl From<String> for ArcStr {
+	fn from(value: String) -> Self {
+		Self(value.into())
+	}
+}
+
+impl<'a> From<&'a str> for ArcStr {
+	fn from(value: &'a str) -> Self {
+		Self(value.into())
+	}
+}
+
+impl From<Arc<str>> for ArcStr {
+	fn from(value: Arc<str>) -> Self {
+		Self(value)
+	}
+}
+
+impl From<ArcStr> for Arc<str> {
+	fn from(value: ArcStr) -> Self {
+		value.0
+	}
+}
+
+impl fmt::Display for ArcStr {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		self.0.fmt(f)
+	}
+}
+
+impl fmt::Debug for ArcStr {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		self.0.fmt(f)
+	}
+}
+
+impl RdfDisplay for ArcStr {
+	fn rdf_fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		self.as_str().rdf_fmt(f)
+	}
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for ArcStr {
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: serde::Serializer,
+	{
+		serializer.serialize_str(self.as_str())
+	}
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for ArcStr {
+	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+	where
+		D: serde::Deserializer<'de>,
+	{
+		String::deserialize(deserializer).map(Into::into)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{Literal, LiteralType, XSD_STRING};
+
+	#[test]
+	fn shared_literal_value() {
+		let literal: Literal<_, ArcStr> = Literal::new(
+			ArcStr::from("a \"large\" value"),
+			LiteralType::Any(XSD_STRING.to_owned()),
+		);
+		let copy = literal.clone();
+
+		assert!(Arc::ptr_eq(&literal.value.0, &copy.value.0));
+		assert_eq!(copy.as_str(), "a \"large\" value");
+		assert_eq!(copy.to_string(), "\"a \\\"large\\\" value\"")
+	}
+}
@@ -0,0 +1,348 @@
+use std::{
+	borrow::Cow,
+	cmp::Ordering,
+	hash::{Hash, Hasher},
+};
+
+use super::xsd;
+use crate::{Literal, LiteralType, XSD_BOOLEAN, XSD_DATE_TIME, XSD_DOUBLE, XSD_FLOAT, XSD_STRING};
+
+/// Literal compared by value.
+///
+/// The structural comparison of [`Literal`] compares lexical forms, so that
+/// `"1"^^xsd:integer` and `"01"^^xsd:integer` are different. This wrapper
+/// compares literals according to the value they denote instead, for the
+/// following datatype families:
+///   - numeric (`xsd:decimal`, `xsd:integer` and its derived datatypes such
+///     as `xsd:long` or `xsd:nonNegativeInteger`, `xsd:double`,
+///     `xsd:float`). Decimals and integers are compared exactly, and are
+///     only converted to `f64` when compared with an `xsd:double` or
+///     `xsd:float`;
+///   - `xsd:boolean`;
+///   - `xsd:string`;
+///   - `xsd:dateTime`, values without timezone being assumed to be in UTC.
+///
+/// Other literals, and literals whose lexical form is invalid for their
+/// datatype, are compared structurally. Literals of different families are
+/// not comparable.
+#[derive(Debug, Clone, Copy)]
+pub struct ByValue<'a>(pub &'a Literal);
+
+/// Value of a literal, as used by [`ByValue`].
+enum Value<'a> {
+	/// Canonical `xsd:decimal` form.
+	Decimal(String),
+	Floating(f64),
+	Boolean(bool),
+	String(&'a str),
+	DateTime(f64),
+	Other(&'a Literal),
+}
+
+impl<'a> ByValue<'a> {
+	fn value(&self) -> Value<'a> {
+		let literal = self.0;
+		let value = match &literal.type_ {
+			LiteralType::Any(ty) => {
+				let ty = ty.as_iri();
+				let value = literal.value.as_str();
+				if ty == XSD_STRING {
+					Some(Value::String(value))
+				} else if ty == XSD_BOOLEAN {
+					xsd::parse_boolean(value).map(Value::Boolean)
+				} else if ty == XSD_DATE_TIME {
+					xsd::parse_date_time(value).map(Value::DateTime)
+				} else if let Some(decimal) = xsd::parse_decimal(ty, value) {
+					Some(Value::Decimal(decimal))
+				} else {
+					xsd::parse_floating(ty, value).map(Value::Floating)
+				}
+			}
+			_ => None,
+		};
+
+		value.unwrap_or(Value::Other(literal))
+	}
+}
+
+impl<'a> PartialEq for ByValue<'a> {
+	fn eq(&self, other: &Self) -> bool {
+		self.partial_cmp(other) == Some(Ordering::Equal)
+	}
+}
+
+impl<'a> PartialOrd for ByValue<'a> {
+	fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+		match (self.value(), other.value()) {
+			(Value::Decimal(a), Value::Decimal(b)) => Some(xsd::cmp_decimal(&a, &b)),
+			(Value::Decimal(a), Value::Floating(b)) => a.parse::<f64>().ok()?.partial_cmp(&b),
+			(Value::Floating(a), Value::Decimal(b)) => a.partial_cmp(&b.parse::<f64>().ok()?),
+			(Value::Floating(a), Value::Floating(b)) => a.partial_cmp(&b),
+			(Value::Boolean(a), Value::Boolean(b)) => Some(a.cmp(&b)),
+			(Value::String(a), Value::String(b)) => Some(a.cmp(b)),
+			(Value::DateTime(a), Value::DateTime(b)) => a.partial_cmp(&b),
+			(Value::Other(a), Value::Other(b)) => Some(a.cmp(b)),
+			_ => None,
+		}
+	}
+}
+
+/// Literal hashed and compared by canonical value.
+///
+/// Contrarily to the structural `Hash` implementation of [`Literal`], the
+/// lexical form of literals with a recognized datatype (`xsd:boolean`,
+/// `xsd:integer`, `xsd:decimal`, `xsd:double`, `xsd:float` and
+/// `xsd:dateTime`) is normalized to its canonical form before being hashed or
+/// compared, so that value-equal literals such as `"1"^^xsd:integer` and
+/// `"01"^^xsd:integer` can be used interchangeably as map keys.
+///
+/// In addition, `xsd:dateTime` values with a timezone are converted to UTC,
+/// and the negative zero of `xsd:double` and `xsd:float` is folded into the
+/// positive zero. Values without timezone are only equal to values without
+/// timezone.
+///
+/// Contrarily to [`ByValue`], literals of different datatypes are never
+/// equal, which makes this wrapper a proper [`Eq`] and [`Hash`] key.
+#[derive(Debug, Clone, Copy)]
+pub struct ValueHash<'a>(pub &'a Literal);
+
+impl<'a> ValueHash<'a> {
+	/// Returns the canonical lexical form of the literal, or its lexical form
+	/// if the datatype is not recognized.
+	fn canonical_value(&self) -> Cow<'a, str> {
+		let literal = self.0;
+		match &literal.type_ {
+			LiteralType::Any(ty) => {
+				let ty = ty.as_iri();
+				if ty == XSD_DATE_TIME {
+					xsd::utc_date_time(&literal.value)
+				} else if (ty == XSD_DOUBLE || ty == XSD_FLOAT)
+					&& xsd::parse_double(&literal.value) == Some(0.0)
+				{
+					Some("0.0E0".to_owned())
+				} else {
+					xsd::canonical(ty, &literal.value)
+				}
+				.map(Cow::Owned)
+			}
+			_ => None,
+		}
+		.unwrap_or(Cow::Borrowed(literal.value.as_str()))
+	}
+}
+
+impl<'a> PartialEq for ValueHash<'a> {
+	fn eq(&self, other: &Self) -> bool {
+		self.0.type_ == other.0.type_ && self.canonical_value() == other.canonical_value()
+	}
+}
+
+impl<'a> Eq for ValueHash<'a> {}
+
+impl<'a> Hash for ValueHash<'a> {
+	fn hash<H: Hasher>(&self, state: &mut H) {
+		self.0.type_.hash(state);
+		self.canonical_value().hash(state)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{XSD_DECIMAL, XSD_INTEGER};
+	use iref::{Iri, IriBuf};
+	use static_iref::iri;
+
+	fn literal(value: &str, ty: &Iri) -> Literal {
+		Literal::new(value.to_owned(), LiteralType::Any(ty.to_owned()))
+	}
+
+	#[test]
+	fn numeric() {
+		let a = literal("1", XSD_INTEGER);
+		let b = literal("01", XSD_INTEGER);
+		let c = literal("1.0", XSD_DECIMAL);
+		let d = literal("1.5E0", XSD_DOUBLE);
+
+		assert_ne!(a, b);
+		assert_eq!(a.by_value(), b.by_value());
+		assert_eq!(a.by_value(), c.by_value());
+		assert!(c.by_value() < d.by_value());
+
+		let nan = literal("NaN", XSD_DOUBLE);
+		assert_ne!(nan.by_value(), nan.by_value())
+	}
+
+	#[test]
+	fn exact_numeric() {
+		assert_ne!(
+			literal("9007199254740993", XSD_INTEGER).by_value(),
+			literal("9007199254740992", XSD_INTEGER).by_value()
+		);
+		assert!(
+			literal("0.1", XSD_DECIMAL).by_value()
+				< literal("0.100000000000000000001", XSD_DECIMAL).by_value()
+		);
+
+		let ordered = [
+			literal("-10", XSD_INTEGER),
+			literal("-2.5", XSD_DECIMAL),
+			literal("-2", XSD_INTEGER),
+			literal("0", XSD_INTEGER),
+			literal("0.05", XSD_DECIMAL),
+			literal("0.5", XSD_DECIMAL),
+			literal("3", XSD_INTEGER),
+			literal("12", XSD_INTEGER),
+		];
+		for (i, a) in ordered.iter().enumerate() {
+			for (j, b) in ordered.iter().enumerate() {
+				assert_eq!(a.by_value().partial_cmp(&b.by_value()), Some(i.cmp(&j)))
+			}
+		}
+
+		assert_eq!(
+			literal("-0.0", XSD_DECIMAL).by_value(),
+			literal("0", XSD_INTEGER).by_value()
+		)
+	}
+
+	#[test]
+	fn derived_integers() {
+		let xsd = |name: &str| format!("http://www.w3.org/2001/XMLSchema#{name}");
+		let typed = |value: &str, ty: &str| {
+			Literal::new(
+				value.to_owned(),
+				LiteralType::Any(IriBuf::new(xsd(ty)).unwrap()),
+			)
+		};
+
+		let one = literal("1", XSD_INTEGER);
+		for ty in [
+			"long",
+			"int",
+			"short",
+			"byte",
+			"unsignedByte",
+			"positiveInteger",
+		] {
+			assert_eq!(typed("01", ty).by_value(), one.by_value())
+		}
+		assert!(typed("-1", "negativeInteger").by_value() < one.by_value());
+
+		for (value, ty) in [
+			("300", "byte"),
+			("-1", "nonNegativeInteger"),
+			("0", "positiveInteger"),
+			("99999999999999999999999999999999999999999", "unsignedLong"),
+		] {
+			assert_eq!(
+				typed(value, ty)
+					.by_value()
+					.partial_cmp(&literal(value, XSD_INTEGER).by_value()),
+				None
+			)
+		}
+
+		assert_eq!(
+			typed(
+				"99999999999999999999999999999999999999999",
+				"nonNegativeInteger"
+			)
+			.by_value(),
+			literal("99999999999999999999999999999999999999999", XSD_INTEGER).by_value()
+		)
+	}
+
+	#[test]
+	fn boolean_and_string() {
+		assert_eq!(
+			literal("1", XSD_BOOLEAN).by_value(),
+			literal("true", XSD_BOOLEAN).by_value()
+		);
+		assert!(literal("a", XSD_STRING).by_value() < literal("b", XSD_STRING).by_value());
+		assert_eq!(
+			literal("1", XSD_STRING)
+				.by_value()
+				.partial_cmp(&literal("1", XSD_INTEGER).by_value()),
+			None
+		)
+	}
+
+	#[test]
+	fn date_time() {
+		let a = literal("2024-01-01T12:00:00Z", XSD_DATE_TIME);
+		let b = literal("2024-01-01T13:00:00.000+01:00", XSD_DATE_TIME);
+		let c = literal("2023-12-31T23:59:59Z", XSD_DATE_TIME);
+
+		assert_eq!(a.by_value(), b.by_value());
+		assert!(c.by_value() < a.by_value())
+	}
+
+	#[test]
+	fn lexical_fallback() {
+		let ty = iri!("http://example.org/#type");
+		assert_ne!(literal("1", ty).by_value(), literal("01", ty).by_value());
+		assert_eq!(literal("1", ty).by_value(), literal("1", ty).by_value());
+
+		let invalid = literal("one", XSD_INTEGER);
+		assert_eq!(invalid.by_value(), invalid.by_value());
+		assert_eq!(
+			invalid
+				.by_value()
+				.partial_cmp(&literal("1", XSD_INTEGER).by_value()),
+			None
+		)
+	}
+
+	#[test]
+	fn value_hash() {
+		use std::collections::HashMap;
+
+		let a = literal("1", XSD_INTEGER);
+		let b = literal("01", XSD_INTEGER);
+		let c = literal("1.0", XSD_DECIMAL);
+		let t = literal("true", XSD_BOOLEAN);
+
+		let mut map = HashMap::new();
+		map.insert(a.value_hash_key(), "a");
+		map.insert(t.value_hash_key(), "t");
+		assert_eq!(map.get(&b.value_hash_key()), Some(&"a"));
+		assert_eq!(
+			map.get(&literal("1", XSD_BOOLEAN).value_hash_key()),
+			Some(&"t")
+		);
+		assert_eq!(map.get(&c.value_hash_key()), None);
+
+		let ty = iri!("http://example.org/#type");
+		assert_ne!(
+			literal("1", ty).value_hash_key(),
+			literal("01", ty).value_hash_key()
+		)
+	}
+
+	#[test]
+	fn value_hash_normalization() {
+		use std::collections::HashSet;
+
+		fn keys(literals: &[Literal]) -> HashSet<ValueHash> {
+			literals.iter().map(Literal::value_hash_key).collect()
+		}
+
+		let utc = literal("2024-01-01T12:00:00Z", XSD_DATE_TIME);
+		let offset = literal("2024-01-01T13:00:00.000+01:00", XSD_DATE_TIME);
+		let previous_day = literal("2024-01-01T02:00:00+03:00", XSD_DATE_TIME);
+		let shifted = literal("2023-12-31T23:00:00Z", XSD_DATE_TIME);
+		let local = literal("2024-01-01T12:00:00", XSD_DATE_TIME);
+		assert_eq!(utc.by_value(), offset.by_value());
+		assert_eq!(utc.value_hash_key(), offset.value_hash_key());
+		assert_eq!(previous_day.value_hash_key(), shifted.value_hash_key());
+		assert_ne!(utc.value_hash_key(), local.value_hash_key());
+		assert_eq!(keys(&[utc, offset, previous_day, shifted, local]).len(), 3);
+
+		let zero = literal("0.0", XSD_DOUBLE);
+		let negative_zero = literal("-0.0E0", XSD_DOUBLE);
+		assert_eq!(zero.by_value(), negative_zero.by_value());
+		assert_eq!(zero.value_hash_key(), negative_zero.value_hash_key());
+		assert_eq!(keys(&[zero, negative_zero]).len(), 1)
+	}
+}
@@ -0,0 +1,165 @@
+//! Conversions between literals and [`chrono`] date and time values.
+use chrono::{
+	DateTime, Days, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, SecondsFormat, TimeZone,
+};
+
+use super::{xsd, Literal, LiteralType};
+use crate::{XSD_DATE, XSD_DATE_TIME, XSD_TIME};
+
+/// Parses an `xsd:date` lexical value, without timezone.
+fn parse_date(date: &str) -> Option<NaiveDate> {
+	NaiveDate::parse_from_str(date, "%Y-%m-%d").ok()
+}
+
+/// Parses an `xsd:time` lexical value, without timezone.
+///
+/// Returns `true` along with midnight for the end of day `24:00:00` value.
+fn parse_time(time: &str) -> Option<(NaiveTime, bool)> {
+	let (hms, fraction) = time.split_once('.').unwrap_or((time, ""));
+	if hms == "24:00:00" && fraction.bytes().all(|b| b == b'0') {
+		NaiveTime::from_hms_opt(0, 0, 0).map(|t| (t, true))
+	} else {
+		NaiveTime::parse_from_str(time, "%H:%M:%S%.f")
+			.ok()
+			.map(|t| (t, false))
+	}
+}
+
+/// Parses a timezone, as split by [`xsd::split_timezone`].
+///
+/// Values without timezone are assumed to be in UTC.
+fn parse_timezone(timezone: &str) -> Option<FixedOffset> {
+	match timezone {
+		"" | "Z" => FixedOffset::east_opt(0),
+		_ => {
+			let (h, m) = timezone[1..].split_once(':')?;
+			let (h, m): (i32, i32) = (h.parse().ok()?, m.parse().ok()?);
+			if h > 14 || m > 59 || (h == 14 && m > 0) {
+				return None;
+			}
+
+			let seconds = h * 3600 + m * 60;
+			if timezone.starts_with('-') {
+				FixedOffset::west_opt(seconds)
+			} else {
+				FixedOffset::east_opt(seconds)
+			}
+		}
+	}
+}
+
+impl Literal {
+	/// Creates an `xsd:dateTime` literal from the given date and time, using
+	/// its canonical lexical form.
+	pub fn from_datetime<Tz: TimeZone>(datetime: DateTime<Tz>) -> Self
+	where
+		Tz::Offset: std::fmt::Display,
+	{
+		let value = datetime.to_rfc3339_opts(SecondsFormat::AutoSi, true);
+		let value = xsd::canonical(XSD_DATE_TIME, &value).unwrap_or(value);
+		Self::new(value, LiteralType::Any(XSD_DATE_TIME.to_owned()))
+	}
+
+	/// Returns the value of this literal if it is an `xsd:dateTime`.
+	///
+	/// Values without timezone are assumed to be in UTC, and the `24:00:00`
+	/// time denotes the first instant of the following day.
+	pub fn as_datetime(&self) -> Option<DateTime<FixedOffset>> {
+		if !self.has_datatype(XSD_DATE_TIME) {
+			return None;
+		}
+
+		let (date, time, timezone) = xsd::split_date_time(&self.value)?;
+		let (time, end_of_day) = parse_time(time)?;
+		let mut date = parse_date(date)?;
+		if end_of_day {
+			date = date.checked_add_days(Days::new(1))?
+		}
+
+		parse_timezone(timezone)?
+			.from_local_datetime(&NaiveDateTime::new(date, time))
+			.single()
+	}
+
+	/// Returns the value of this literal if it is an `xsd:date`.
+	///
+	/// The timezone, if any, is checked but not returned.
+	pub fn as_date(&self) -> Option<NaiveDate> {
+		if !self.has_datatype(XSD_DATE) {
+			return None;
+		}
+
+		let (date, timezone) = xsd::split_timezone(&self.value);
+		parse_timezone(timezone)?;
+		parse_date(date)
+	}
+
+	/// Returns the value of this literal if it is an `xsd:time`.
+	///
+	/// The timezone, if any, is checked but not returned. The `24:00:00` time
+	/// is returned as midnight.
+	pub fn as_time(&self) -> Option<NaiveTime> {
+		if !self.has_datatype(XSD_TIME) {
+			return None;
+		}
+
+		let (time, timezone) = xsd::split_timezone(&self.value);
+		parse_timezone(timezone)?;
+		parse_time(time).map(|(time, _)| time)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use chrono::{Timelike, Utc};
+
+	#[test]
+	fn datetime() {
+		let literal = Literal::new_typed("2024-02-29T23:59:59.250-05:00", XSD_DATE_TIME.to_owned());
+		let datetime = literal.as_datetime().unwrap();
+		assert_eq!(datetime.offset().local_minus_utc(), -5 * 3600);
+		assert_eq!(datetime.nanosecond(), 250_000_000);
+		assert_eq!(
+			Literal::from_datetime(datetime).value,
+			"2024-02-29T23:59:59.25-05:00"
+		);
+
+		let utc = Literal::new_typed("2024-02-29T24:00:00", XSD_DATE_TIME.to_owned());
+		let utc = utc.as_datetime().unwrap().with_timezone(&Utc);
+		assert_eq!(Literal::from_datetime(utc).value, "2024-03-01T00:00:00Z");
+
+		for value in [
+			"2024-02-30T00:00:00Z",
+			"2024-02-29",
+			"2024-02-29T00:00:00+15:00",
+		] {
+			assert_eq!(
+				Literal::new_typed(value, XSD_DATE_TIME.to_owned()).as_datetime(),
+				None
+			)
+		}
+
+		assert_eq!(
+			Literal::new_string("2024-02-29T00:00:00Z").as_datetime(),
+			None
+		)
+	}
+
+	#[test]
+	fn date_and_time() {
+		let date = Literal::new_typed("2024-02-29+01:00", XSD_DATE.to_owned());
+		assert_eq!(date.as_date(), NaiveDate::from_ymd_opt(2024, 2, 29));
+
+		let time = Literal::new_typed("13:20:00.5Z", XSD_TIME.to_owned());
+		assert_eq!(
+			time.as_time(),
+			NaiveTime::from_hms_milli_opt(13, 20, 0, 500)
+		);
+
+		let time = Literal::new_typed("24:00:00", XSD_TIME.to_owned());
+		assert_eq!(time.as_time(), NaiveTime::from_hms_opt(0, 0, 0));
+
+		assert_eq!(time.as_date(), None)
+	}
+}
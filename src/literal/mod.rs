@@ -2,32 +2,47 @@ use crate::vocabulary::{
 	EmbedIntoVocabulary, EmbeddedIntoVocabulary, ExtractFromVocabulary, ExtractedFromVocabulary,
 	IriVocabulary, IriVocabularyMut, LiteralVocabularyMut,
 };
-use crate::{IsXsdStringIri, RdfDisplay};
+use crate::{
+	parse::Parser, IsXsdStringIri, RdfDisplay, XSD_BASE64_BINARY, XSD_BOOLEAN, XSD_DOUBLE,
+	XSD_HEX_BINARY, XSD_INTEGER, XSD_STRING,
+};
 use educe::Educe;
-use iref::IriBuf;
-use langtag::LangTag;
+use iref::{Iri, IriBuf};
+use langtag::{InvalidLangTag, LangTag, LangTagBuf};
 use std::borrow::Borrow;
 use std::fmt;
+use std::str::FromStr;
 
 #[cfg(feature = "contextual")]
 use contextual::DisplayWithContext;
 
+mod arc_str;
+mod by_value;
+#[cfg(feature = "chrono")]
+mod datetime;
 mod r#type;
+mod xsd;
+
+pub use arc_str::*;
+pub use by_value::*;
 pub use r#type::*;
 
 /// RDF Literal.
+///
+/// The literal value is a `String` by default. Any other string type `S`,
+/// such as [`ArcStr`], can be used instead.
 #[derive(Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Debug)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-pub struct Literal<I = IriBuf> {
+pub struct Literal<I = IriBuf, S = String> {
 	/// Literal value.
-	pub value: String,
+	pub value: S,
 
 	/// Literal type.
 	pub type_: LiteralType<I>,
 }
 
-impl<I> Literal<I> {
-	pub fn new(value: String, type_: LiteralType<I>) -> Self {
+impl<I, S> Literal<I, S> {
+	pub fn new(value: S, type_: LiteralType<I>) -> Self {
 		Self { value, type_ }
 	}
 
@@ -43,30 +58,22 @@ impl<I> Literal<I> {
 		self.type_
 	}
 
-	pub fn as_value(&self) -> &String {
+	pub fn as_value(&self) -> &S {
 		&self.value
 	}
 
-	pub fn as_value_mut(&mut self) -> &mut String {
+	pub fn as_value_mut(&mut self) -> &mut S {
 		&mut self.value
 	}
 
-	pub fn into_value(self) -> String {
+	pub fn into_value(self) -> S {
 		self.value
 	}
 
-	pub fn into_parts(self) -> (String, LiteralType<I>) {
+	pub fn into_parts(self) -> (S, LiteralType<I>) {
 		(self.value, self.type_)
 	}
 
-	pub fn as_str(&self) -> &str {
-		self.value.as_ref()
-	}
-
-	pub fn as_bytes(&self) -> &[u8] {
-		self.value.as_ref()
-	}
-
 	pub fn is_lang_string(&self) -> bool {
 		self.type_.is_lang_string()
 	}
@@ -75,7 +82,30 @@ impl<I> Literal<I> {
 		self.type_.lang_tag()
 	}
 
-	pub fn insert_type_into_vocabulary<V>(self, vocabulary: &mut V) -> Literal<I::Embedded>
+	pub fn direction(&self) -> Option<Direction> {
+		self.type_.direction()
+	}
+
+	/// Checks if this is a language string whose tag matches the given basic
+	/// language range (`*`, `en`, `en-*`, etc.).
+	///
+	/// See [`LiteralType::lang_matches_range`].
+	pub fn lang_matches_range(&self, range: &str) -> bool {
+		self.type_.lang_matches_range(range)
+	}
+
+	/// Returns the language tag of this literal, if it is a language string,
+	/// with its case normalized.
+	///
+	/// See [`LiteralType::normalized_lang_tag`].
+	pub fn normalized_lang_tag(&self) -> Option<LangTagBuf> {
+		self.type_.normalized_lang_tag()
+	}
+
+	/// Embeds the literal type into the given vocabulary.
+	///
+	/// The literal value is moved into the result, it is never cloned.
+	pub fn insert_type_into_vocabulary<V>(self, vocabulary: &mut V) -> Literal<I::Embedded, S>
 	where
 		I: EmbedIntoVocabulary<V>,
 	{
@@ -85,18 +115,266 @@ impl<I> Literal<I> {
 		}
 	}
 
-	pub fn inserted_type_into_vocabulary<V>(&self, vocabulary: &mut V) -> Literal<I::Embedded>
+	/// Embeds the literal type into the given vocabulary, returning the
+	/// literal value and the embedded type separately.
+	///
+	/// Just like [`Self::insert_type_into_vocabulary`], the literal value is
+	/// moved out of the literal without being cloned.
+	pub fn take_value_insert_type<V>(self, vocabulary: &mut V) -> (S, LiteralType<I::Embedded>)
+	where
+		I: EmbedIntoVocabulary<V>,
+	{
+		(self.value, self.type_.embed_into_vocabulary(vocabulary))
+	}
+
+	/// Embeds a copy of the literal type into the given vocabulary.
+	///
+	/// Since the literal is borrowed, the literal value is cloned. Use
+	/// [`Self::insert_type_into_vocabulary`] to avoid this allocation when the
+	/// literal can be consumed.
+	pub fn inserted_type_into_vocabulary<V>(&self, vocabulary: &mut V) -> Literal<I::Embedded, S>
 	where
 		I: EmbeddedIntoVocabulary<V>,
+		S: Clone,
 	{
 		Literal {
 			value: self.value.clone(),
 			type_: self.type_.embedded_into_vocabulary(vocabulary),
 		}
 	}
+}
+
+impl<I, S: AsRef<str>> Literal<I, S> {
+	pub fn as_str(&self) -> &str {
+		self.value.as_ref()
+	}
+
+	pub fn as_bytes(&self) -> &[u8] {
+		self.as_str().as_bytes()
+	}
 
 	pub fn as_ref(&self) -> LiteralRef<I> {
-		LiteralRef::new(&self.value, self.type_.as_ref())
+		LiteralRef::new(self.as_str(), self.type_.as_ref())
+	}
+}
+
+impl FromStr for Literal {
+	type Err = InvalidLiteral;
+
+	/// Parses a single N-Triples literal.
+	///
+	/// See [`Literal::from_ntriples`].
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		Self::from_ntriples(s)
+	}
+}
+
+/// Error returned by [`Literal::parse_typed`] when the lexical value is not
+/// valid for the given datatype.
+#[derive(Debug, thiserror::Error)]
+#[error("invalid lexical value `{value}` for datatype <{datatype}>")]
+pub struct TypedLiteralError {
+	/// Invalid lexical value.
+	pub value: String,
+
+	/// Datatype.
+	pub datatype: IriBuf,
+}
+
+/// Error returned by [`Literal::from_ntriples`] when the input is not a valid
+/// N-Triples literal.
+///
+/// Each variant holds the byte offset at which the input is invalid.
+#[derive(Debug, thiserror::Error)]
+pub enum InvalidLiteral {
+	#[error("expected a quoted string at byte offset {0}")]
+	Expected(usize),
+
+	#[error("unterminated string starting at byte offset {0}")]
+	Unterminated(usize),
+
+	#[error("invalid escape sequence at byte offset {0}")]
+	Escape(usize),
+
+	#[error("invalid datatype IRI at byte offset {0}")]
+	Datatype(usize),
+
+	#[error("invalid language tag at byte offset {0}")]
+	LanguageTag(usize),
+
+	#[error("unexpected character at byte offset {0}")]
+	Trailing(usize),
+}
+
+impl InvalidLiteral {
+	/// Returns the byte offset at which the input is invalid.
+	pub fn offset(&self) -> usize {
+		match self {
+			Self::Expected(i)
+			| Self::Unterminated(i)
+			| Self::Escape(i)
+			| Self::Datatype(i)
+			| Self::LanguageTag(i)
+			| Self::Trailing(i) => *i,
+		}
+	}
+}
+
+impl Literal {
+	/// Creates a new literal with the given datatype.
+	pub fn new_typed(value: impl Into<String>, datatype: IriBuf) -> Self {
+		Self::new(value.into(), LiteralType::Any(datatype))
+	}
+
+	/// Creates a new `xsd:string` literal.
+	pub fn new_string(value: impl Into<String>) -> Self {
+		Self::new_typed(value, XSD_STRING.to_owned())
+	}
+
+	/// Creates a new language-tagged string literal.
+	pub fn new_lang(value: impl Into<String>, tag: LangTagBuf) -> Self {
+		Self::new(value.into(), LiteralType::LangString(tag))
+	}
+
+	/// Creates a new language-tagged string literal, checking that the given
+	/// language tag is valid.
+	pub fn try_new_lang(
+		value: impl Into<String>,
+		tag: &str,
+	) -> Result<Self, InvalidLangTag<String>> {
+		Ok(Self::new_lang(value, LangTagBuf::new(tag.to_owned())?))
+	}
+
+	/// Parses a single N-Triples literal, with its optional datatype or
+	/// language tag.
+	///
+	/// Escape sequences in the quoted value and in the datatype IRI are
+	/// decoded.
+	pub fn from_ntriples(s: &str) -> Result<Self, InvalidLiteral> {
+		let mut parser = Parser::new(s);
+		let literal = parser.literal()?;
+		parser.finish().map_err(InvalidLiteral::Trailing)?;
+		Ok(literal)
+	}
+
+	/// Creates a new typed literal, checking that the lexical value is valid
+	/// for the given datatype.
+	///
+	/// Lexical values are validated for the common XSD datatypes listed in
+	/// the [`datatype`](crate::datatype) module. The lexical value of any
+	/// other datatype is accepted as is.
+	pub fn parse_typed(lexical: &str, datatype: &Iri) -> Result<Self, TypedLiteralError> {
+		if xsd::is_valid(datatype, lexical) == Some(false) {
+			Err(TypedLiteralError {
+				value: lexical.to_owned(),
+				datatype: datatype.to_owned(),
+			})
+		} else {
+			Ok(Self::new(
+				lexical.to_owned(),
+				LiteralType::Any(datatype.to_owned()),
+			))
+		}
+	}
+
+	/// Creates an `xsd:base64Binary` literal encoding the given bytes.
+	pub fn new_base64(bytes: &[u8]) -> Self {
+		Self::new(
+			xsd::encode_base64(bytes),
+			LiteralType::Any(XSD_BASE64_BINARY.to_owned()),
+		)
+	}
+
+	/// Creates an `xsd:hexBinary` literal encoding the given bytes.
+	pub fn new_hex_binary(bytes: &[u8]) -> Self {
+		Self::new(
+			xsd::encode_hex(bytes),
+			LiteralType::Any(XSD_HEX_BINARY.to_owned()),
+		)
+	}
+
+	/// Decodes the bytes of an `xsd:base64Binary` or `xsd:hexBinary` literal.
+	///
+	/// Returns `None` if the literal has another datatype, or if its lexical
+	/// value is invalid.
+	pub fn decode_binary(&self) -> Option<Vec<u8>> {
+		if self.has_datatype(XSD_BASE64_BINARY) {
+			xsd::decode_base64(&self.value)
+		} else if self.has_datatype(XSD_HEX_BINARY) {
+			xsd::decode_hex(&self.value)
+		} else {
+			None
+		}
+	}
+
+	fn has_datatype(&self, iri: &Iri) -> bool {
+		matches!(&self.type_, LiteralType::Any(ty) if ty == iri)
+	}
+
+	/// Returns the value of this literal if it is an `xsd:integer` fitting in
+	/// an `i64`.
+	pub fn as_xsd_integer(&self) -> Option<i64> {
+		if self.has_datatype(XSD_INTEGER) {
+			xsd::parse_integer(&self.value)
+		} else {
+			None
+		}
+	}
+
+	/// Returns the value of this literal if it is an `xsd:double`.
+	///
+	/// The special values `INF`, `-INF` and `NaN` are supported.
+	pub fn as_xsd_double(&self) -> Option<f64> {
+		if self.has_datatype(XSD_DOUBLE) {
+			xsd::parse_double(&self.value)
+		} else {
+			None
+		}
+	}
+
+	/// Returns a copy of this literal with its lexical value rewritten to the
+	/// canonical form of its datatype.
+	///
+	/// Supported datatypes are `xsd:boolean`, `xsd:integer`, `xsd:decimal`,
+	/// `xsd:double`, `xsd:float` and `xsd:dateTime`. Literals of any other
+	/// datatype, or whose lexical value is invalid, are returned unchanged.
+	pub fn canonicalized(&self) -> Literal {
+		let value = match &self.type_ {
+			LiteralType::Any(ty) => xsd::canonical(ty.as_iri(), &self.value),
+			_ => None,
+		};
+
+		Literal::new(
+			value.unwrap_or_else(|| self.value.clone()),
+			self.type_.clone(),
+		)
+	}
+
+	/// Wraps this literal so that it is compared by value rather than by
+	/// lexical form.
+	///
+	/// See [`ByValue`] for the supported datatypes.
+	pub fn by_value(&self) -> ByValue {
+		ByValue(self)
+	}
+
+	/// Wraps this literal so that it is hashed and compared by canonical
+	/// value, to be used as a value-keyed map key.
+	///
+	/// See [`ValueHash`] for the supported datatypes.
+	pub fn value_hash_key(&self) -> ValueHash {
+		ValueHash(self)
+	}
+
+	/// Returns the value of this literal if it is an `xsd:boolean`.
+	///
+	/// Both `true`/`false` and `1`/`0` lexical forms are supported.
+	pub fn as_xsd_boolean(&self) -> Option<bool> {
+		if self.has_datatype(XSD_BOOLEAN) {
+			xsd::parse_boolean(&self.value)
+		} else {
+			None
+		}
 	}
 }
 
@@ -144,19 +422,19 @@ impl<V: IriVocabulary> ExtractedFromVocabulary<V> for Literal<V::Iri> {
 	}
 }
 
-impl<I> Borrow<str> for Literal<I> {
+impl<I, S: AsRef<str>> Borrow<str> for Literal<I, S> {
 	fn borrow(&self) -> &str {
 		self.as_str()
 	}
 }
 
-impl<I> AsRef<str> for Literal<I> {
+impl<I, S: AsRef<str>> AsRef<str> for Literal<I, S> {
 	fn as_ref(&self) -> &str {
 		self.as_str()
 	}
 }
 
-impl fmt::Display for Literal {
+impl<S: RdfDisplay> fmt::Display for Literal<IriBuf, S> {
 	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
 		self.value.rdf_fmt(f)?;
 		if self.type_.is_xsd_string() {
@@ -167,7 +445,7 @@ impl fmt::Display for Literal {
 	}
 }
 
-impl<I: RdfDisplay + IsXsdStringIri> RdfDisplay for Literal<I> {
+impl<I: RdfDisplay + IsXsdStringIri, S: RdfDisplay> RdfDisplay for Literal<I, S> {
 	fn rdf_fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
 		self.value.rdf_fmt(f)?;
 		if self.type_.is_xsd_string() {
@@ -179,7 +457,8 @@ impl<I: RdfDisplay + IsXsdStringIri> RdfDisplay for Literal<I> {
 }
 
 #[cfg(feature = "contextual")]
-impl<V: crate::vocabulary::IriVocabulary> DisplayWithContext<V> for Literal<V::Iri>
+impl<V: crate::vocabulary::IriVocabulary, S: RdfDisplay> DisplayWithContext<V>
+	for Literal<V::Iri, S>
 where
 	V::Iri: crate::RdfDisplayWithContext<V>,
 {
@@ -195,7 +474,8 @@ where
 }
 
 #[cfg(feature = "contextual")]
-impl<V: crate::vocabulary::IriVocabulary> crate::RdfDisplayWithContext<V> for Literal<V::Iri>
+impl<V: crate::vocabulary::IriVocabulary, S: RdfDisplay> crate::RdfDisplayWithContext<V>
+	for Literal<V::Iri, S>
 where
 	V::Iri: crate::RdfDisplayWithContext<V>,
 {
@@ -266,6 +546,10 @@ impl<'a, I> LiteralRef<'a, I> {
 		self.type_.lang_tag()
 	}
 
+	pub fn direction(&self) -> Option<Direction> {
+		self.type_.direction()
+	}
+
 	pub fn insert_type_into_vocabulary<V>(self, vocabulary: &mut V) -> Literal<I::Embedded>
 	where
 		I: EmbeddedIntoVocabulary<V>,
@@ -415,3 +699,367 @@ where
 		}
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		Id, LexicalTriple, Term, Triple, RDF_LANG_STRING, XSD_DATE_TIME, XSD_DECIMAL, XSD_FLOAT,
+	};
+	use static_iref::iri;
+
+	fn lang_string(value: &str, tag: &str) -> Literal {
+		Literal::new(
+			value.to_owned(),
+			LiteralType::LangString(LangTagBuf::new(tag.to_owned()).unwrap()),
+		)
+	}
+
+	fn typed(value: &str, ty: &Iri) -> Literal {
+		Literal::new(value.to_owned(), LiteralType::Any(ty.to_owned()))
+	}
+
+	#[test]
+	fn constructors() {
+		assert_eq!(
+			Literal::new_typed("1", XSD_INTEGER.to_owned()),
+			typed("1", XSD_INTEGER)
+		);
+		assert_eq!(Literal::new_string("a"), typed("a", XSD_STRING));
+		assert_eq!(
+			Literal::new_lang("chat", LangTagBuf::new("fr".to_owned()).unwrap()),
+			lang_string("chat", "fr")
+		);
+		assert_eq!(
+			Literal::try_new_lang("chat", "fr").unwrap(),
+			lang_string("chat", "fr")
+		);
+		assert!(Literal::try_new_lang("chat", "fr_FR").is_err())
+	}
+
+	#[test]
+	fn blank_id_from_ntriples() {
+		assert_eq!(
+			crate::BlankIdBuf::from_ntriples("_:b0").unwrap().as_str(),
+			"_:b0"
+		);
+		assert!(crate::BlankIdBuf::from_ntriples("b0").is_err())
+	}
+
+	#[test]
+	fn from_ntriples() {
+		let literal: Literal = "\"a\\tb\\u00E9\"^^<http://example.org/#t\\u0079pe>"
+			.parse()
+			.unwrap();
+		assert_eq!(literal.as_str(), "a\tb\u{e9}");
+		assert_eq!(
+			literal.type_,
+			LiteralType::Any(iri!("http://example.org/#type").to_owned())
+		);
+
+		let literal = Literal::from_ntriples("\"chat\"@fr").unwrap();
+		assert_eq!(literal.lang_tag().unwrap().as_str(), "fr");
+
+		assert!(matches!(
+			Literal::from_ntriples("\"abc"),
+			Err(InvalidLiteral::Unterminated(0))
+		));
+		assert!(matches!(
+			Literal::from_ntriples("\"a\\qb\""),
+			Err(InvalidLiteral::Escape(3))
+		));
+		assert!(matches!(
+			Literal::from_ntriples("\"a\"^^<not an iri>"),
+			Err(InvalidLiteral::Datatype(5))
+		));
+		assert!(matches!(
+			Literal::from_ntriples("\"a\" ."),
+			Err(InvalidLiteral::Trailing(3))
+		))
+	}
+
+	#[test]
+	fn parse_typed() {
+		let literal = Literal::parse_typed("-12", XSD_INTEGER).unwrap();
+		assert_eq!(literal.as_xsd_integer(), Some(-12));
+
+		assert!(Literal::parse_typed("twelve", XSD_INTEGER).is_err());
+		assert!(Literal::parse_typed("1.5", XSD_INTEGER).is_err());
+		assert!(Literal::parse_typed("1.5", XSD_DECIMAL).is_ok());
+		assert!(Literal::parse_typed("1.5E3", XSD_DOUBLE).is_ok());
+
+		let unknown = iri!("http://example.org/#unknown");
+		let literal = Literal::parse_typed("anything goes", unknown).unwrap();
+		assert_eq!(literal.value, "anything goes");
+		assert!(literal.type_.is_iri(&unknown.to_owned()))
+	}
+
+	#[test]
+	fn xsd_values() {
+		assert_eq!(typed("-42", XSD_INTEGER).as_xsd_integer(), Some(-42));
+		assert_eq!(typed("+042", XSD_INTEGER).as_xsd_integer(), Some(42));
+		assert_eq!(typed("4.2", XSD_INTEGER).as_xsd_integer(), None);
+		assert_eq!(typed("42", XSD_DOUBLE).as_xsd_integer(), None);
+
+		assert_eq!(typed("1.5e2", XSD_DOUBLE).as_xsd_double(), Some(150.0));
+		assert_eq!(typed(".5", XSD_DOUBLE).as_xsd_double(), Some(0.5));
+		assert_eq!(
+			typed("-INF", XSD_DOUBLE).as_xsd_double(),
+			Some(f64::NEG_INFINITY)
+		);
+		assert!(typed("NaN", XSD_DOUBLE).as_xsd_double().unwrap().is_nan());
+		assert_eq!(typed("inf", XSD_DOUBLE).as_xsd_double(), None);
+		assert_eq!(typed("1e", XSD_DOUBLE).as_xsd_double(), None);
+
+		assert_eq!(typed("true", XSD_BOOLEAN).as_xsd_boolean(), Some(true));
+		assert_eq!(typed("0", XSD_BOOLEAN).as_xsd_boolean(), Some(false));
+		assert_eq!(typed("True", XSD_BOOLEAN).as_xsd_boolean(), None);
+	}
+
+	#[test]
+	fn canonicalized() {
+		let cases = [
+			("1", XSD_BOOLEAN, "true"),
+			("+0042", XSD_INTEGER, "42"),
+			("-000", XSD_INTEGER, "0"),
+			("01.500", XSD_DECIMAL, "1.5"),
+			("1.00", XSD_DECIMAL, "1.0"),
+			(".5", XSD_DECIMAL, "0.5"),
+			("-0.0", XSD_DECIMAL, "0.0"),
+			("100", XSD_DOUBLE, "1.0E2"),
+			("1.5e2", XSD_DOUBLE, "1.5E2"),
+			("+INF", XSD_DOUBLE, "INF"),
+			("0.1", XSD_FLOAT, "1.0E-1"),
+			(
+				"2020-01-01T10:00:00.500+00:00",
+				XSD_DATE_TIME,
+				"2020-01-01T10:00:00.5Z",
+			),
+			(
+				"2020-01-01T10:00:00.0-05:00",
+				XSD_DATE_TIME,
+				"2020-01-01T10:00:00-05:00",
+			),
+			(
+				"2024-02-29T24:00:00.000Z",
+				XSD_DATE_TIME,
+				"2024-02-29T24:00:00Z",
+			),
+			(
+				"2023-02-29T10:00:00.0Z",
+				XSD_DATE_TIME,
+				"2023-02-29T10:00:00.0Z",
+			),
+			(
+				"2020-13-01T10:00:00.0Z",
+				XSD_DATE_TIME,
+				"2020-13-01T10:00:00.0Z",
+			),
+			(
+				"2020-04-31T10:00:00.0Z",
+				XSD_DATE_TIME,
+				"2020-04-31T10:00:00.0Z",
+			),
+			(
+				"2020-01-01T24:00:01.0Z",
+				XSD_DATE_TIME,
+				"2020-01-01T24:00:01.0Z",
+			),
+			(
+				"2020-01-01T10:60:00.0Z",
+				XSD_DATE_TIME,
+				"2020-01-01T10:60:00.0Z",
+			),
+			(
+				"2020-01-01T10:00:60.0Z",
+				XSD_DATE_TIME,
+				"2020-01-01T10:00:60.0Z",
+			),
+			(
+				"2020-01-01T10:00:00.0+14:30",
+				XSD_DATE_TIME,
+				"2020-01-01T10:00:00.0+14:30",
+			),
+			("abc", XSD_INTEGER, "abc"),
+			("1.00", iri!("http://example.org/#unknown"), "1.00"),
+		];
+
+		for (value, ty, expected) in cases {
+			let literal = typed(value, ty).canonicalized();
+			assert_eq!(literal.value, expected);
+			assert!(literal.type_.is_iri(&ty.to_owned()))
+		}
+
+		assert_eq!(
+			typed("1.0", XSD_DECIMAL).canonicalized(),
+			typed("1.00", XSD_DECIMAL).canonicalized()
+		)
+	}
+
+	#[test]
+	fn lang_matches_range() {
+		let literal = lang_string("color", "en-US");
+		assert!(literal.lang_matches_range("*"));
+		assert!(literal.lang_matches_range("en"));
+		assert!(literal.lang_matches_range("EN-*"));
+		assert!(literal.lang_matches_range("en-us"));
+		assert!(!literal.lang_matches_range("fr"));
+		assert!(!literal.lang_matches_range("e"));
+		assert!(!literal.lang_matches_range("en-GB"));
+	}
+
+	#[test]
+	fn directional_lang_string() {
+		let literal = Literal::new(
+			"hello".to_owned(),
+			LiteralType::DirLangString(LangTagBuf::new("en".to_owned()).unwrap(), Direction::Ltr),
+		);
+		assert!(literal.is_lang_string());
+		assert_eq!(literal.lang_tag().unwrap().as_str(), "en");
+		assert_eq!(literal.direction(), Some(Direction::Ltr));
+		assert!(literal.lang_matches_range("en"));
+		assert_eq!(literal.to_string(), "\"hello\"@en--ltr")
+	}
+
+	#[test]
+	fn normalized_lang_tag() {
+		let normalized = |tag: &str| {
+			lang_string("a", tag)
+				.normalized_lang_tag()
+				.unwrap()
+				.as_str()
+				.to_owned()
+		};
+
+		assert_eq!(normalized("EN-us"), "en-US");
+		assert_eq!(normalized("zh-hant-tw"), "zh-Hant-TW");
+		assert_eq!(normalized("en-a-BB-x-CcCc"), "en-a-bb-x-cccc");
+
+		let a = lang_string("color", "en-us");
+		let b = lang_string("color", "EN-US");
+		assert_ne!(
+			a.lang_tag().unwrap().as_str(),
+			b.lang_tag().unwrap().as_str()
+		);
+		assert_eq!(
+			a.normalized_lang_tag().unwrap().as_str(),
+			b.normalized_lang_tag().unwrap().as_str()
+		);
+		assert!(a.lang_matches_range("en"));
+		assert_eq!(Literal::new_hex_binary(b"").normalized_lang_tag(), None)
+	}
+
+	#[test]
+	fn lang_matches_primary() {
+		let a = lang_string("color", "en-US");
+		let b = lang_string("colour", "en-GB");
+		let c = lang_string("couleur", "fr");
+		assert!(a.type_.lang_matches_primary(&b.type_));
+		assert!(!a.type_.lang_matches_primary(&c.type_));
+	}
+
+	#[test]
+	fn from_datatype_and_lang() {
+		let en = LangTagBuf::new("en".to_owned()).unwrap();
+		assert_eq!(
+			LiteralType::from_datatype_and_lang(RDF_LANG_STRING.to_owned(), Some(en.clone()))
+				.unwrap(),
+			LiteralType::LangString(en.clone())
+		);
+		assert_eq!(
+			LiteralType::from_datatype_and_lang(XSD_INTEGER.to_owned(), None).unwrap(),
+			LiteralType::Any(XSD_INTEGER.to_owned())
+		);
+		assert!(matches!(
+			LiteralType::from_datatype_and_lang(RDF_LANG_STRING.to_owned(), None),
+			Err(InvalidLiteralType::MissingLanguageTag)
+		));
+		assert!(matches!(
+			LiteralType::from_datatype_and_lang(XSD_STRING.to_owned(), Some(en)),
+			Err(InvalidLiteralType::UnexpectedLanguageTag(_))
+		))
+	}
+
+	#[test]
+	fn rdf_suffix() {
+		assert_eq!(
+			typed("1", XSD_INTEGER).type_.rdf_suffix().to_string(),
+			"^^<http://www.w3.org/2001/XMLSchema#integer>"
+		);
+		assert_eq!(
+			typed("a", XSD_STRING).type_.rdf_suffix().to_string(),
+			"^^<http://www.w3.org/2001/XMLSchema#string>"
+		);
+		assert_eq!(
+			lang_string("color", "en-US").type_.rdf_suffix().to_string(),
+			"@en-US"
+		)
+	}
+
+	#[test]
+	fn insert_type_does_not_clone_value() {
+		let literal = Literal::new(
+			"a large literal value".to_owned(),
+			LiteralType::Any(iri!("http://example.org/#type").to_owned()),
+		);
+		let value_ptr = literal.value.as_ptr();
+
+		let inserted = literal.insert_type_into_vocabulary(&mut ());
+		assert_eq!(inserted.value.as_ptr(), value_ptr);
+
+		let (value, _) = inserted.take_value_insert_type(&mut ());
+		assert_eq!(value.as_ptr(), value_ptr)
+	}
+
+	#[test]
+	fn escaped_value_round_trip() {
+		let literal = Literal::new(
+			"say \"hi\"\n\tto \\ everyone\x01".to_owned(),
+			LiteralType::Any(XSD_STRING.to_owned()),
+		);
+		assert_eq!(
+			literal.to_string(),
+			"\"say \\\"hi\\\"\\n\\tto \\\\ everyone\\u0001\""
+		);
+
+		let triple: LexicalTriple = Triple(
+			Id::Iri(iri!("http://example.org/#s").to_owned()),
+			iri!("http://example.org/#p").to_owned(),
+			Term::Literal(literal),
+		);
+		let parsed: LexicalTriple = format!("{triple} .").parse().unwrap();
+		assert_eq!(parsed, triple)
+	}
+
+	#[test]
+	fn binary() {
+		for bytes in [&b""[..], b"f", b"fo", b"foo", b"foob", b"\x00\xff\x10"] {
+			assert_eq!(Literal::new_base64(bytes).decode_binary().unwrap(), bytes);
+			assert_eq!(
+				Literal::new_hex_binary(bytes).decode_binary().unwrap(),
+				bytes
+			);
+		}
+
+		assert_eq!(Literal::new_base64(b"foob").value, "Zm9vYg==");
+		assert_eq!(Literal::new_hex_binary(b"\x0f\xa0").value, "0FA0");
+
+		let literal = Literal::parse_typed("Zm9v YmFy", XSD_BASE64_BINARY).unwrap();
+		assert_eq!(literal.decode_binary().unwrap(), b"foobar");
+		let literal = Literal::parse_typed("Zm9vYmE =", XSD_BASE64_BINARY).unwrap();
+		assert_eq!(literal.decode_binary().unwrap(), b"fooba");
+		for invalid in [
+			"Zm9=Yg==",
+			"Zm9v\nYmFy",
+			"Zm9v  YmFy",
+			" Zm9vYmFy",
+			"Zm9vYmFy ",
+			"Zm9vYh==",
+			"Zm9vYmF=",
+		] {
+			assert!(Literal::parse_typed(invalid, XSD_BASE64_BINARY).is_err())
+		}
+		assert!(Literal::parse_typed("0fa", XSD_HEX_BINARY).is_err());
+		assert_eq!(Literal::new_base64(b"a").as_xsd_integer(), None)
+	}
+}
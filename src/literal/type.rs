@@ -9,9 +9,35 @@ use crate::{
 		EmbedIntoVocabulary, EmbeddedIntoVocabulary, ExtractFromVocabulary,
 		ExtractedFromVocabulary, IriVocabulary,
 	},
-	IsXsdStringIri, RdfDisplay, XSD_STRING,
+	IsXsdStringIri, RdfDisplay, RDF_LANG_STRING, XSD_STRING,
 };
 
+/// Base direction of a directional language string.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Direction {
+	/// Left-to-right.
+	Ltr,
+
+	/// Right-to-left.
+	Rtl,
+}
+
+impl Direction {
+	pub fn as_str(&self) -> &'static str {
+		match self {
+			Self::Ltr => "ltr",
+			Self::Rtl => "rtl",
+		}
+	}
+}
+
+impl fmt::Display for Direction {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		self.as_str().fmt(f)
+	}
+}
+
 /// RDF literal type.
 #[derive(Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Debug)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -21,24 +47,82 @@ pub enum LiteralType<I = IriBuf> {
 
 	/// Language string.
 	LangString(LangTagBuf),
+
+	/// Directional language string.
+	DirLangString(LangTagBuf, Direction),
 }
 
 impl<I> LiteralType<I> {
 	pub fn is_lang_string(&self) -> bool {
-		matches!(self, Self::LangString(_))
+		matches!(self, Self::LangString(_) | Self::DirLangString(_, _))
 	}
 
 	pub fn lang_tag(&self) -> Option<&LangTag> {
 		match self {
-			Self::LangString(tag) => Some(tag),
+			Self::LangString(tag) | Self::DirLangString(tag, _) => Some(tag),
 			_ => None,
 		}
 	}
 
+	/// Returns the base direction of this type if it is a directional
+	/// language string.
+	pub fn direction(&self) -> Option<Direction> {
+		match self {
+			Self::DirLangString(_, direction) => Some(*direction),
+			_ => None,
+		}
+	}
+
+	/// Checks if both types are language strings sharing the same primary
+	/// language subtag, ignoring case and any other subtag (e.g. `en-US` and
+	/// `en-GB`).
+	pub fn lang_matches_primary<J>(&self, other: &LiteralType<J>) -> bool {
+		match (self.lang_tag(), other.lang_tag()) {
+			(Some(a), Some(b)) => primary_language(a).eq_ignore_ascii_case(primary_language(b)),
+			_ => false,
+		}
+	}
+
+	/// Checks if this is a language string whose tag matches the given basic
+	/// language range, as defined by [RFC 4647 Section 3.3.1][1].
+	///
+	/// The range `*` matches any language tag, and a range such as `en` (or
+	/// `en-*`) matches `en` and any tag starting with `en-`.
+	///
+	/// [1]: <https://www.rfc-editor.org/rfc/rfc4647#section-3.3.1>
+	pub fn lang_matches_range(&self, range: &str) -> bool {
+		self.lang_tag()
+			.is_some_and(|tag| lang_tag_matches_range(tag, range))
+	}
+
+	/// Returns the language tag of this type, if it is a language string,
+	/// with its case normalized according to [RFC 5646 Section 2.1.1][1].
+	///
+	/// Region subtags are upper case (`en-US`), script subtags are title case
+	/// (`zh-Hant`) and every other subtag is lower case.
+	///
+	/// [1]: <https://www.rfc-editor.org/rfc/rfc5646#section-2.1.1>
+	pub fn normalized_lang_tag(&self) -> Option<LangTagBuf> {
+		self.lang_tag().map(normalize_lang_tag)
+	}
+
+	/// Displays this type as it appears after the value of a literal, with its
+	/// `^^` or `@` separator (e.g. `^^<http://www.w3.org/2001/XMLSchema#integer>`
+	/// or `@en`).
+	///
+	/// Unlike the formatting of a whole [`Literal`](crate::Literal), the
+	/// `xsd:string` datatype is not omitted.
+	pub fn rdf_suffix(&self) -> impl '_ + fmt::Display
+	where
+		I: RdfDisplay,
+	{
+		self.rdf_display()
+	}
+
 	pub fn is_xsd_string_with(&self, vocabulary: &impl IriVocabulary<Iri = I>) -> bool {
 		match self {
 			Self::Any(i) => vocabulary.iri(i).is_some_and(|iri| iri == XSD_STRING),
-			Self::LangString(_) => false,
+			Self::LangString(_) | Self::DirLangString(_, _) => false,
 		}
 	}
 
@@ -48,7 +132,7 @@ impl<I> LiteralType<I> {
 	{
 		match self {
 			Self::Any(iri) => iri.is_xsd_string_iri(),
-			Self::LangString(_) => false,
+			Self::LangString(_) | Self::DirLangString(_, _) => false,
 		}
 	}
 
@@ -58,7 +142,7 @@ impl<I> LiteralType<I> {
 	{
 		match self {
 			Self::Any(i) => i == iri,
-			Self::LangString(_) => false,
+			Self::LangString(_) | Self::DirLangString(_, _) => false,
 		}
 	}
 
@@ -66,6 +150,7 @@ impl<I> LiteralType<I> {
 		match self {
 			Self::Any(i) => LiteralTypeRef::Any(i),
 			Self::LangString(l) => LiteralTypeRef::LangString(l),
+			Self::DirLangString(l, d) => LiteralTypeRef::DirLangString(l, *d),
 		}
 	}
 
@@ -76,24 +161,58 @@ impl<I> LiteralType<I> {
 		match self {
 			Self::Any(i) => LexicalLiteralTypeRef::Any(vocabulary.iri(i).unwrap()),
 			Self::LangString(l) => LexicalLiteralTypeRef::LangString(l),
+			Self::DirLangString(l, d) => LexicalLiteralTypeRef::DirLangString(l, *d),
 		}
 	}
 }
 
 impl LiteralType {
+	/// Builds a literal type from a datatype IRI and an optional language tag,
+	/// as found in serializations where both are given separately.
+	///
+	/// The result is a language string if and only if the datatype is
+	/// `rdf:langString`. A language tag is required with this datatype, and
+	/// rejected with any other.
+	pub fn from_datatype_and_lang(
+		datatype: IriBuf,
+		lang: Option<LangTagBuf>,
+	) -> Result<Self, InvalidLiteralType> {
+		let is_lang_string = datatype == *RDF_LANG_STRING;
+		match lang {
+			Some(tag) if is_lang_string => Ok(Self::LangString(tag)),
+			Some(_) => Err(InvalidLiteralType::UnexpectedLanguageTag(datatype)),
+			None if is_lang_string => Err(InvalidLiteralType::MissingLanguageTag),
+			None => Ok(Self::Any(datatype)),
+		}
+	}
+
 	pub fn as_lexical_type_ref(&self) -> LexicalLiteralTypeRef {
 		match self {
 			Self::Any(i) => LexicalLiteralTypeRef::Any(i),
 			Self::LangString(l) => LexicalLiteralTypeRef::LangString(l),
+			Self::DirLangString(l, d) => LexicalLiteralTypeRef::DirLangString(l, *d),
 		}
 	}
 }
 
+/// Error returned by [`LiteralType::from_datatype_and_lang`].
+#[derive(Debug, thiserror::Error)]
+pub enum InvalidLiteralType {
+	#[error("missing language tag for `rdf:langString` datatype")]
+	MissingLanguageTag,
+
+	#[error("unexpected language tag for datatype <{0}>")]
+	UnexpectedLanguageTag(IriBuf),
+}
+
 impl<'a, I: PartialEq> PartialEq<LiteralTypeRef<'a, I>> for LiteralType<I> {
 	fn eq(&self, other: &LiteralTypeRef<'a, I>) -> bool {
 		match (self, *other) {
 			(Self::Any(a), LiteralTypeRef::Any(b)) => a == b,
 			(Self::LangString(a), LiteralTypeRef::LangString(b)) => a == b,
+			(Self::DirLangString(a, da), LiteralTypeRef::DirLangString(b, db)) => {
+				a == b && *da == db
+			}
 			_ => false,
 		}
 	}
@@ -106,6 +225,7 @@ impl<V, I: EmbedIntoVocabulary<V>> EmbedIntoVocabulary<V> for LiteralType<I> {
 		match self {
 			Self::Any(i) => LiteralType::Any(i.embed_into_vocabulary(vocabulary)),
 			Self::LangString(l) => LiteralType::LangString(l),
+			Self::DirLangString(l, d) => LiteralType::DirLangString(l, d),
 		}
 	}
 }
@@ -117,6 +237,7 @@ impl<V, I: EmbeddedIntoVocabulary<V>> EmbeddedIntoVocabulary<V> for LiteralType<
 		match self {
 			Self::Any(i) => LiteralType::Any(i.embedded_into_vocabulary(vocabulary)),
 			Self::LangString(l) => LiteralType::LangString(l.clone()),
+			Self::DirLangString(l, d) => LiteralType::DirLangString(l.clone(), *d),
 		}
 	}
 }
@@ -128,6 +249,7 @@ impl<V: IriVocabulary> ExtractFromVocabulary<V> for LiteralType<V::Iri> {
 		match self {
 			Self::Any(t) => LiteralType::Any(vocabulary.owned_iri(t).ok().unwrap()),
 			Self::LangString(t) => LiteralType::LangString(t),
+			Self::DirLangString(t, d) => LiteralType::DirLangString(t, d),
 		}
 	}
 }
@@ -139,6 +261,7 @@ impl<V: IriVocabulary> ExtractedFromVocabulary<V> for LiteralType<V::Iri> {
 		match self {
 			Self::Any(t) => LiteralType::Any(vocabulary.iri(t).unwrap().to_owned()),
 			Self::LangString(t) => LiteralType::LangString(t.clone()),
+			Self::DirLangString(t, d) => LiteralType::DirLangString(t.clone(), *d),
 		}
 	}
 }
@@ -154,6 +277,11 @@ impl<I: RdfDisplay> RdfDisplay for LiteralType<I> {
 				write!(f, "@")?;
 				tag.rdf_fmt(f)
 			}
+			Self::DirLangString(tag, direction) => {
+				write!(f, "@")?;
+				tag.rdf_fmt(f)?;
+				write!(f, "--{direction}")
+			}
 		}
 	}
 }
@@ -170,10 +298,66 @@ impl<T: crate::RdfDisplayWithContext<V>, V> crate::RdfDisplayWithContext<V> for
 				write!(f, "@")?;
 				tag.rdf_fmt_with(vocabulary, f)
 			}
+			Self::DirLangString(tag, direction) => {
+				write!(f, "@")?;
+				tag.rdf_fmt_with(vocabulary, f)?;
+				write!(f, "--{direction}")
+			}
 		}
 	}
 }
 
+/// Returns the primary language subtag of the given language tag.
+fn primary_language(tag: &LangTag) -> &str {
+	let tag = tag.as_str();
+	tag.split('-').next().unwrap_or(tag)
+}
+
+/// Normalizes the case of the given language tag.
+///
+/// Subtags following a singleton (extensions and private use) are always lower
+/// case.
+fn normalize_lang_tag(tag: &LangTag) -> LangTagBuf {
+	let mut result = String::with_capacity(tag.as_str().len());
+	let mut after_singleton = false;
+
+	for (i, subtag) in tag.as_str().split('-').enumerate() {
+		if i > 0 {
+			result.push('-')
+		}
+
+		if i > 0 && !after_singleton && subtag.len() == 2 {
+			result.push_str(&subtag.to_ascii_uppercase())
+		} else if i > 0 && !after_singleton && subtag.len() == 4 {
+			result.push_str(&subtag[..1].to_ascii_uppercase());
+			result.push_str(&subtag[1..].to_ascii_lowercase())
+		} else {
+			result.push_str(&subtag.to_ascii_lowercase())
+		}
+
+		after_singleton |= subtag.len() == 1
+	}
+
+	LangTagBuf::new(result).unwrap()
+}
+
+/// Checks if the given language tag matches the given basic language range.
+fn lang_tag_matches_range(tag: &LangTag, range: &str) -> bool {
+	let range = range.strip_suffix("-*").unwrap_or(range);
+	if range == "*" {
+		return true;
+	}
+
+	let tag = tag.as_str();
+	match tag.get(..range.len()) {
+		Some(prefix) => {
+			prefix.eq_ignore_ascii_case(range)
+				&& (tag.len() == range.len() || tag.as_bytes()[range.len()] == b'-')
+		}
+		None => false,
+	}
+}
+
 /// RDF literal type reference.
 #[derive(Educe, PartialEq, Eq, Hash, PartialOrd, Ord, Debug)]
 #[educe(Clone, Copy)]
@@ -184,16 +368,28 @@ pub enum LiteralTypeRef<'a, I = IriBuf> {
 
 	/// Language string.
 	LangString(&'a LangTag),
+
+	/// Directional language string.
+	DirLangString(&'a LangTag, Direction),
 }
 
 impl<'a, I> LiteralTypeRef<'a, I> {
 	pub fn is_lang_string(&self) -> bool {
-		matches!(self, Self::LangString(_))
+		matches!(self, Self::LangString(_) | Self::DirLangString(_, _))
 	}
 
 	pub fn lang_tag(&self) -> Option<&'a LangTag> {
 		match self {
-			Self::LangString(tag) => Some(tag),
+			Self::LangString(tag) | Self::DirLangString(tag, _) => Some(tag),
+			_ => None,
+		}
+	}
+
+	/// Returns the base direction of this type if it is a directional
+	/// language string.
+	pub fn direction(&self) -> Option<Direction> {
+		match self {
+			Self::DirLangString(_, direction) => Some(*direction),
 			_ => None,
 		}
 	}
@@ -201,7 +397,7 @@ impl<'a, I> LiteralTypeRef<'a, I> {
 	pub fn is_xsd_string_with(&self, vocabulary: &impl IriVocabulary<Iri = I>) -> bool {
 		match self {
 			Self::Any(i) => vocabulary.iri(i).is_some_and(|iri| iri == XSD_STRING),
-			Self::LangString(_) => false,
+			Self::LangString(_) | Self::DirLangString(_, _) => false,
 		}
 	}
 
@@ -211,7 +407,7 @@ impl<'a, I> LiteralTypeRef<'a, I> {
 	{
 		match self {
 			Self::Any(iri) => iri.is_xsd_string_iri(),
-			Self::LangString(_) => false,
+			Self::LangString(_) | Self::DirLangString(_, _) => false,
 		}
 	}
 
@@ -221,7 +417,7 @@ impl<'a, I> LiteralTypeRef<'a, I> {
 	{
 		match self {
 			Self::Any(i) => *i == iri,
-			Self::LangString(_) => false,
+			Self::LangString(_) | Self::DirLangString(_, _) => false,
 		}
 	}
 
@@ -232,6 +428,7 @@ impl<'a, I> LiteralTypeRef<'a, I> {
 		match self {
 			Self::Any(i) => LexicalLiteralTypeRef::Any(vocabulary.iri(i).unwrap()),
 			Self::LangString(l) => LexicalLiteralTypeRef::LangString(l),
+			Self::DirLangString(l, d) => LexicalLiteralTypeRef::DirLangString(l, *d),
 		}
 	}
 }
@@ -241,6 +438,7 @@ impl<'a, I: ToOwned> LiteralTypeRef<'a, I> {
 		match self {
 			Self::Any(i) => LiteralType::Any(i.to_owned()),
 			Self::LangString(l) => LiteralType::LangString(l.to_owned()),
+			Self::DirLangString(l, d) => LiteralType::DirLangString(l.to_owned(), d),
 		}
 	}
 }
@@ -253,6 +451,7 @@ impl<'a, I> LiteralTypeRef<'a, I> {
 		match self {
 			Self::Any(i) => LiteralType::Any(i.into()),
 			Self::LangString(l) => LiteralType::LangString(l.to_owned()),
+			Self::DirLangString(l, d) => LiteralType::DirLangString(l.to_owned(), d),
 		}
 	}
 }
@@ -262,6 +461,7 @@ impl<'a> LiteralTypeRef<'a> {
 		match self {
 			Self::Any(i) => LexicalLiteralTypeRef::Any(i),
 			Self::LangString(l) => LexicalLiteralTypeRef::LangString(l),
+			Self::DirLangString(l, d) => LexicalLiteralTypeRef::DirLangString(l, *d),
 		}
 	}
 }
@@ -271,6 +471,9 @@ impl<'a, I: PartialEq> PartialEq<LiteralType<I>> for LiteralTypeRef<'a, I> {
 		match (*self, other) {
 			(Self::Any(a), LiteralType::Any(b)) => a == b,
 			(Self::LangString(a), LiteralType::LangString(b)) => a == b.as_lang_tag(),
+			(Self::DirLangString(a, da), LiteralType::DirLangString(b, db)) => {
+				a == b.as_lang_tag() && da == *db
+			}
 			_ => false,
 		}
 	}
@@ -283,6 +486,7 @@ impl<'a, V, I: EmbeddedIntoVocabulary<V>> EmbedIntoVocabulary<V> for LiteralType
 		match self {
 			Self::Any(i) => LiteralType::Any(i.embedded_into_vocabulary(vocabulary)),
 			Self::LangString(l) => LiteralType::LangString(l.to_owned()),
+			Self::DirLangString(l, d) => LiteralType::DirLangString(l.to_owned(), d),
 		}
 	}
 }
@@ -294,6 +498,7 @@ impl<'a, V, I: EmbeddedIntoVocabulary<V>> EmbeddedIntoVocabulary<V> for LiteralT
 		match *self {
 			Self::Any(i) => LiteralType::Any(i.embedded_into_vocabulary(vocabulary)),
 			Self::LangString(l) => LiteralType::LangString(l.to_owned()),
+			Self::DirLangString(l, d) => LiteralType::DirLangString(l.to_owned(), d),
 		}
 	}
 }
@@ -305,6 +510,7 @@ impl<'a, V: IriVocabulary> ExtractFromVocabulary<V> for LiteralTypeRef<'a, V::Ir
 		match self {
 			Self::Any(t) => LiteralType::Any(vocabulary.iri(t).unwrap().to_owned()),
 			Self::LangString(t) => LiteralType::LangString(t.to_owned()),
+			Self::DirLangString(t, d) => LiteralType::DirLangString(t.to_owned(), d),
 		}
 	}
 }
@@ -316,6 +522,7 @@ impl<'a, V: IriVocabulary> ExtractedFromVocabulary<V> for LiteralTypeRef<'a, V::
 		match *self {
 			Self::Any(t) => LiteralType::Any(vocabulary.iri(t).unwrap().to_owned()),
 			Self::LangString(t) => LiteralType::LangString(t.to_owned()),
+			Self::DirLangString(t, d) => LiteralType::DirLangString(t.to_owned(), d),
 		}
 	}
 }
@@ -331,6 +538,11 @@ impl<'a, I: RdfDisplay> RdfDisplay for LiteralTypeRef<'a, I> {
 				write!(f, "@")?;
 				tag.rdf_fmt(f)
 			}
+			Self::DirLangString(tag, direction) => {
+				write!(f, "@")?;
+				tag.rdf_fmt(f)?;
+				write!(f, "--{direction}")
+			}
 		}
 	}
 }
@@ -349,6 +561,11 @@ impl<'a, T: crate::RdfDisplayWithContext<V>, V> crate::RdfDisplayWithContext<V>
 				write!(f, "@")?;
 				tag.rdf_fmt_with(vocabulary, f)
 			}
+			Self::DirLangString(tag, direction) => {
+				write!(f, "@")?;
+				tag.rdf_fmt_with(vocabulary, f)?;
+				write!(f, "--{direction}")
+			}
 		}
 	}
 }
@@ -416,13 +633,16 @@ pub enum LexicalLiteralTypeRef<'a> {
 
 	/// Language string.
 	LangString(&'a LangTag),
+
+	/// Directional language string.
+	DirLangString(&'a LangTag, Direction),
 }
 
 impl<'a> LexicalLiteralTypeRef<'a> {
 	pub fn is_iri(&self, iri: &Iri) -> bool {
 		match self {
 			Self::Any(i) => *i == iri,
-			Self::LangString(_) => false,
+			Self::LangString(_) | Self::DirLangString(_, _) => false,
 		}
 	}
 }
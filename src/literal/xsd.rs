@@ -0,0 +1,711 @@
+//! Lexical forms of common XSD datatypes.
+use std::cmp::Ordering;
+
+use iref::Iri;
+use static_iref::iri;
+
+use crate::{
+	XSD_BASE64_BINARY, XSD_BOOLEAN, XSD_DATE_TIME, XSD_DECIMAL, XSD_DOUBLE, XSD_FLOAT,
+	XSD_HEX_BINARY, XSD_INTEGER, XSD_STRING,
+};
+
+/// Checks that the given value is a non-empty sequence of ASCII digits.
+fn is_digits(value: &str) -> bool {
+	!value.is_empty() && value.bytes().all(|b| b.is_ascii_digit())
+}
+
+/// Removes the leading sign of the given value, if any.
+fn unsigned(value: &str) -> &str {
+	value.strip_prefix(['+', '-']).unwrap_or(value)
+}
+
+/// Checks if the given value is negative, and removes its leading sign.
+fn sign(value: &str) -> (bool, &str) {
+	match value.strip_prefix('-') {
+		Some(value) => (true, value),
+		None => (false, value.strip_prefix('+').unwrap_or(value)),
+	}
+}
+
+/// Checks that the given value is a valid `xsd:integer` lexical form.
+pub(crate) fn is_integer(value: &str) -> bool {
+	is_digits(unsigned(value))
+}
+
+/// Checks that the given value is a valid `xsd:decimal` lexical form.
+pub(crate) fn is_decimal(value: &str) -> bool {
+	let value = unsigned(value);
+	let (integer, fraction) = value.split_once('.').unwrap_or((value, ""));
+	!(integer.is_empty() && fraction.is_empty())
+		&& integer.bytes().all(|b| b.is_ascii_digit())
+		&& fraction.bytes().all(|b| b.is_ascii_digit())
+}
+
+/// Checks that the given value is a valid `xsd:double` or `xsd:float`
+/// lexical form.
+pub(crate) fn is_double(value: &str) -> bool {
+	match value {
+		"INF" | "+INF" | "-INF" | "NaN" => true,
+		_ => match value.split_once(['e', 'E']) {
+			Some((mantissa, exponent)) => is_decimal(mantissa) && is_integer(exponent),
+			None => is_decimal(value),
+		},
+	}
+}
+
+/// Checks that the given value is a valid lexical form for the given
+/// datatype.
+///
+/// Returns `None` if the datatype is unknown.
+pub(crate) fn is_valid(datatype: &Iri, value: &str) -> Option<bool> {
+	if datatype == XSD_STRING {
+		Some(true)
+	} else if datatype == XSD_BOOLEAN {
+		Some(parse_boolean(value).is_some())
+	} else if datatype == XSD_INTEGER {
+		Some(is_integer(value))
+	} else if datatype == XSD_DECIMAL {
+		Some(is_decimal(value))
+	} else if datatype == XSD_DOUBLE || datatype == XSD_FLOAT {
+		Some(is_double(value))
+	} else if datatype == XSD_BASE64_BINARY {
+		Some(decode_base64(value).is_some())
+	} else if datatype == XSD_HEX_BINARY {
+		Some(decode_hex(value).is_some())
+	} else {
+		None
+	}
+}
+
+/// Parses the lexical form of an `xsd:boolean`.
+pub(crate) fn parse_boolean(value: &str) -> Option<bool> {
+	match value {
+		"true" | "1" => Some(true),
+		"false" | "0" => Some(false),
+		_ => None,
+	}
+}
+
+/// Parses the lexical form of an `xsd:integer`.
+///
+/// Returns `None` if the value is not a valid `xsd:integer` or if it does
+/// not fit in an `i64`.
+pub(crate) fn parse_integer(value: &str) -> Option<i64> {
+	if is_integer(value) {
+		value.parse().ok()
+	} else {
+		None
+	}
+}
+
+/// Parses the lexical form of an `xsd:double`.
+pub(crate) fn parse_double(value: &str) -> Option<f64> {
+	match value {
+		"INF" | "+INF" => Some(f64::INFINITY),
+		"-INF" => Some(f64::NEG_INFINITY),
+		"NaN" => Some(f64::NAN),
+		_ if is_double(value) => value.parse().ok(),
+		_ => None,
+	}
+}
+
+/// Base64 digits, in order.
+const BASE64_DIGITS: &[u8; 64] =
+	b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// Encodes the given bytes into the canonical lexical form of an
+/// `xsd:base64Binary`.
+pub(crate) fn encode_base64(bytes: &[u8]) -> String {
+	let mut result = String::with_capacity((bytes.len() + 2) / 3 * 4);
+	for chunk in bytes.chunks(3) {
+		let n = chunk
+			.iter()
+			.chain(std::iter::repeat(&0))
+			.take(3)
+			.fold(0u32, |n, b| n << 8 | u32::from(*b));
+
+		for i in 0..4 {
+			if i <= chunk.len() {
+				let digit = (n >> (18 - 6 * i)) & 0x3f;
+				result.push(BASE64_DIGITS[digit as usize] as char)
+			} else {
+				result.push('=')
+			}
+		}
+	}
+
+	result
+}
+
+/// Decodes the lexical form of an `xsd:base64Binary`.
+///
+/// Single spaces (`#x20`) between characters are ignored. Other whitespace,
+/// and padded values whose unused bits are not zero, are rejected.
+pub(crate) fn decode_base64(value: &str) -> Option<Vec<u8>> {
+	if value.starts_with(' ') || value.ends_with(' ') || value.contains("  ") {
+		return None;
+	}
+
+	let digits: Vec<u8> = value.bytes().filter(|b| *b != b' ').collect();
+	if digits.len() % 4 != 0 {
+		return None;
+	}
+
+	let mut result = Vec::with_capacity(digits.len() / 4 * 3);
+	for (i, chunk) in digits.chunks(4).enumerate() {
+		let padding = chunk.iter().rev().take_while(|b| **b == b'=').count();
+		if padding > 2 || (padding > 0 && (i + 1) * 4 != digits.len()) {
+			return None;
+		}
+
+		let mut n = 0u32;
+		for b in &chunk[..4 - padding] {
+			let digit = match b {
+				b'A'..=b'Z' => b - b'A',
+				b'a'..=b'z' => b - b'a' + 26,
+				b'0'..=b'9' => b - b'0' + 52,
+				b'+' => 62,
+				b'/' => 63,
+				_ => return None,
+			};
+			n = n << 6 | u32::from(digit);
+		}
+
+		if n & ((1 << (2 * padding)) - 1) != 0 {
+			return None;
+		}
+
+		n <<= 6 * padding;
+		result.extend_from_slice(&n.to_be_bytes()[1..4 - padding]);
+	}
+
+	Some(result)
+}
+
+/// Encodes the given bytes into the canonical lexical form of an
+/// `xsd:hexBinary`, using upper case digits.
+pub(crate) fn encode_hex(bytes: &[u8]) -> String {
+	bytes.iter().map(|b| format!("{b:02X}")).collect()
+}
+
+/// Decodes the lexical form of an `xsd:hexBinary`.
+pub(crate) fn decode_hex(value: &str) -> Option<Vec<u8>> {
+	if value.len() % 2 != 0 || !value.bytes().all(|b| b.is_ascii_hexdigit()) {
+		return None;
+	}
+
+	(0..value.len())
+		.step_by(2)
+		.map(|i| u8::from_str_radix(&value[i..i + 2], 16).ok())
+		.collect()
+}
+
+/// Datatypes derived from `xsd:integer`, with their optional inclusive
+/// bounds.
+const DERIVED_INTEGERS: [(&Iri, Option<i128>, Option<i128>); 12] = [
+	(
+		iri!("http://www.w3.org/2001/XMLSchema#nonPositiveInteger"),
+		None,
+		Some(0),
+	),
+	(
+		iri!("http://www.w3.org/2001/XMLSchema#negativeInteger"),
+		None,
+		Some(-1),
+	),
+	(
+		iri!("http://www.w3.org/2001/XMLSchema#long"),
+		Some(i64::MIN as i128),
+		Some(i64::MAX as i128),
+	),
+	(
+		iri!("http://www.w3.org/2001/XMLSchema#int"),
+		Some(i32::MIN as i128),
+		Some(i32::MAX as i128),
+	),
+	(
+		iri!("http://www.w3.org/2001/XMLSchema#short"),
+		Some(i16::MIN as i128),
+		Some(i16::MAX as i128),
+	),
+	(
+		iri!("http://www.w3.org/2001/XMLSchema#byte"),
+		Some(i8::MIN as i128),
+		Some(i8::MAX as i128),
+	),
+	(
+		iri!("http://www.w3.org/2001/XMLSchema#nonNegativeInteger"),
+		Some(0),
+		None,
+	),
+	(
+		iri!("http://www.w3.org/2001/XMLSchema#unsignedLong"),
+		Some(0),
+		Some(u64::MAX as i128),
+	),
+	(
+		iri!("http://www.w3.org/2001/XMLSchema#unsignedInt"),
+		Some(0),
+		Some(u32::MAX as i128),
+	),
+	(
+		iri!("http://www.w3.org/2001/XMLSchema#unsignedShort"),
+		Some(0),
+		Some(u16::MAX as i128),
+	),
+	(
+		iri!("http://www.w3.org/2001/XMLSchema#unsignedByte"),
+		Some(0),
+		Some(u8::MAX as i128),
+	),
+	(
+		iri!("http://www.w3.org/2001/XMLSchema#positiveInteger"),
+		Some(1),
+		None,
+	),
+];
+
+/// Checks that the given valid `xsd:integer` lexical form is within the
+/// given inclusive bounds.
+fn is_in_range(value: &str, min: Option<i128>, max: Option<i128>) -> bool {
+	match value.parse::<i128>() {
+		Ok(n) => !min.is_some_and(|min| n < min) && !max.is_some_and(|max| n > max),
+		// Out of `i128` bounds, only unbounded on that side.
+		Err(_) => {
+			if sign(value).0 {
+				min.is_none()
+			} else {
+				max.is_none()
+			}
+		}
+	}
+}
+
+/// Parses the lexical form of an `xsd:decimal`, `xsd:integer` or of a
+/// datatype derived from `xsd:integer` (such as `xsd:long` or
+/// `xsd:nonNegativeInteger`) into its canonical `xsd:decimal` form.
+///
+/// Returns `None` if the datatype is not one of those, or if the value is not
+/// a valid lexical form for the datatype.
+pub(crate) fn parse_decimal(datatype: &Iri, value: &str) -> Option<String> {
+	let valid = if datatype == XSD_DECIMAL {
+		is_decimal(value)
+	} else if datatype == XSD_INTEGER {
+		is_integer(value)
+	} else {
+		let (_, min, max) = DERIVED_INTEGERS.iter().find(|(ty, _, _)| *ty == datatype)?;
+		is_integer(value) && is_in_range(value, *min, *max)
+	};
+
+	valid.then(|| canonical_decimal(value))
+}
+
+/// Compares two canonical `xsd:decimal` lexical forms by value.
+pub(crate) fn cmp_decimal(a: &str, b: &str) -> Ordering {
+	let (a_negative, a) = sign(a);
+	let (b_negative, b) = sign(b);
+	match (a_negative, b_negative) {
+		(false, true) => Ordering::Greater,
+		(true, false) => Ordering::Less,
+		(negative, _) => {
+			let (a_integer, a_fraction) = a.split_once('.').unwrap_or((a, ""));
+			let (b_integer, b_fraction) = b.split_once('.').unwrap_or((b, ""));
+			let ordering = a_integer
+				.len()
+				.cmp(&b_integer.len())
+				.then_with(|| a_integer.cmp(b_integer))
+				.then_with(|| a_fraction.cmp(b_fraction));
+
+			if negative {
+				ordering.reverse()
+			} else {
+				ordering
+			}
+		}
+	}
+}
+
+/// Parses the lexical form of an `xsd:double` or `xsd:float` into an `f64`.
+///
+/// Returns `None` if the datatype is not one of those, or if the value is not
+/// a valid lexical form for the datatype.
+pub(crate) fn parse_floating(datatype: &Iri, value: &str) -> Option<f64> {
+	if datatype == XSD_DOUBLE {
+		parse_double(value)
+	} else if datatype == XSD_FLOAT {
+		parse_double(value).map(|d| d as f32 as f64)
+	} else {
+		None
+	}
+}
+
+/// Returns the canonical lexical form of the given value for the given
+/// datatype.
+///
+/// Returns `None` if the datatype is unknown or if the value is not a valid
+/// lexical form for the datatype.
+pub(crate) fn canonical(datatype: &Iri, value: &str) -> Option<String> {
+	if datatype == XSD_BOOLEAN {
+		parse_boolean(value).map(|b| b.to_string())
+	} else if datatype == XSD_INTEGER {
+		is_integer(value).then(|| canonical_integer(value))
+	} else if datatype == XSD_DECIMAL {
+		is_decimal(value).then(|| canonical_decimal(value))
+	} else if datatype == XSD_DOUBLE {
+		parse_double(value).map(canonical_floating)
+	} else if datatype == XSD_FLOAT {
+		parse_double(value).map(|d| canonical_floating(d as f32))
+	} else if datatype == XSD_DATE_TIME {
+		canonical_date_time(value)
+	} else {
+		None
+	}
+}
+
+/// Canonical form of a valid `xsd:integer`: no leading sign unless
+/// negative, and no leading zeros.
+fn canonical_integer(value: &str) -> String {
+	let (negative, digits) = sign(value);
+	match digits.trim_start_matches('0') {
+		"" => "0".to_owned(),
+		digits if negative => format!("-{digits}"),
+		digits => digits.to_owned(),
+	}
+}
+
+/// Canonical form of a valid `xsd:decimal`: no leading sign unless
+/// negative, and a decimal point surrounded by at least one digit, without
+/// leading or trailing zeros.
+fn canonical_decimal(value: &str) -> String {
+	let (negative, value) = sign(value);
+	let (integer, fraction) = value.split_once('.').unwrap_or((value, ""));
+
+	let integer = match integer.trim_start_matches('0') {
+		"" => "0",
+		integer => integer,
+	};
+
+	let fraction = match fraction.trim_end_matches('0') {
+		"" => "0",
+		fraction => fraction,
+	};
+
+	if negative && (integer, fraction) != ("0", "0") {
+		format!("-{integer}.{fraction}")
+	} else {
+		format!("{integer}.{fraction}")
+	}
+}
+
+/// Canonical form of an `xsd:double` or `xsd:float`: a decimal mantissa with
+/// a single non-zero digit before the decimal point, followed by an
+/// exponent.
+fn canonical_floating<F: Float>(value: F) -> String {
+	if value.is_nan() {
+		"NaN".to_owned()
+	} else if value.is_infinite() {
+		if value.is_sign_negative() {
+			"-INF".to_owned()
+		} else {
+			"INF".to_owned()
+		}
+	} else {
+		let formatted = format!("{value:E}");
+		let (mantissa, exponent) = formatted.split_once('E').unwrap();
+		if mantissa.contains('.') {
+			formatted
+		} else {
+			format!("{mantissa}.0E{exponent}")
+		}
+	}
+}
+
+trait Float: Copy + std::fmt::UpperExp {
+	fn is_nan(self) -> bool;
+
+	fn is_infinite(self) -> bool;
+
+	fn is_sign_negative(self) -> bool;
+}
+
+impl Float for f64 {
+	fn is_nan(self) -> bool {
+		f64::is_nan(self)
+	}
+
+	fn is_infinite(self) -> bool {
+		f64::is_infinite(self)
+	}
+
+	fn is_sign_negative(self) -> bool {
+		f64::is_sign_negative(self)
+	}
+}
+
+impl Float for f32 {
+	fn is_nan(self) -> bool {
+		f32::is_nan(self)
+	}
+
+	fn is_infinite(self) -> bool {
+		f32::is_infinite(self)
+	}
+
+	fn is_sign_negative(self) -> bool {
+		f32::is_sign_negative(self)
+	}
+}
+
+/// Splits the optional timezone (`Z` or `±hh:mm`) at the end of a date or
+/// time lexical value.
+///
+/// The timezone part is empty if there is no timezone.
+pub(crate) fn split_timezone(value: &str) -> (&str, &str) {
+	match value.strip_suffix('Z') {
+		Some(value) => (value, "Z"),
+		None if value.len() > 6
+			&& matches!(value.as_bytes()[value.len() - 6], b'+' | b'-')
+			&& value.as_bytes()[value.len() - 3] == b':' =>
+		{
+			value.split_at(value.len() - 6)
+		}
+		None => (value, ""),
+	}
+}
+
+/// Parses a two-digit date or time field.
+fn two_digits(value: &str) -> Option<u32> {
+	if value.len() == 2 && is_digits(value) {
+		value.parse().ok()
+	} else {
+		None
+	}
+}
+
+/// Number of days in the given month of a year, the year being given by its
+/// unsigned digits.
+fn days_in_month(year: &str, month: u32) -> u32 {
+	match month {
+		2 => {
+			// The leap year rules only depend on the year modulo 400, hence on
+			// its last 4 digits.
+			let year: u32 = year[year.len() - 4..].parse().unwrap();
+			if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) {
+				29
+			} else {
+				28
+			}
+		}
+		4 | 6 | 9 | 11 => 30,
+		_ => 31,
+	}
+}
+
+/// Splits an `xsd:dateTime` into its date, time and timezone parts.
+///
+/// Returns `None` if the value is not a valid `xsd:dateTime`, including when
+/// one of its fields is out of range. The timezone part is empty if there is
+/// no timezone.
+pub(crate) fn split_date_time(value: &str) -> Option<(&str, &str, &str)> {
+	let (date, rest) = value.split_once('T')?;
+	let (time, timezone) = split_timezone(rest);
+
+	let mut date_parts = date.strip_prefix('-').unwrap_or(date).split('-');
+	let valid_date = match (
+		date_parts.next(),
+		date_parts.next(),
+		date_parts.next(),
+		date_parts.next(),
+	) {
+		(Some(y), Some(m), Some(d), None) if y.len() >= 4 && is_digits(y) => {
+			match (two_digits(m), two_digits(d)) {
+				(Some(m @ 1..=12), Some(d)) => (1..=days_in_month(y, m)).contains(&d),
+				_ => false,
+			}
+		}
+		_ => false,
+	};
+
+	let (hms, fraction) = time.split_once('.').unwrap_or((time, "0"));
+	let mut time_parts = hms.split(':');
+	let valid_time = is_digits(fraction)
+		&& match (
+			time_parts.next(),
+			time_parts.next(),
+			time_parts.next(),
+			time_parts.next(),
+		) {
+			(Some(h), Some(m), Some(s), None) => {
+				match (two_digits(h), two_digits(m), two_digits(s)) {
+					(Some(0..=23), Some(0..=59), Some(0..=59)) => true,
+					// `24:00:00` denotes the end of the day.
+					(Some(24), Some(0), Some(0)) => fraction.bytes().all(|b| b == b'0'),
+					_ => false,
+				}
+			}
+			_ => false,
+		};
+
+	let valid_timezone = match timezone {
+		"" | "Z" => true,
+		_ => {
+			let (h, m) = timezone[1..].split_once(':').unwrap_or(("", ""));
+			matches!(
+				(two_digits(h), two_digits(m)),
+				(Some(0..=13), Some(0..=59)) | (Some(14), Some(0))
+			)
+		}
+	};
+
+	(valid_date && valid_time && valid_timezone).then_some((date, time, timezone))
+}
+
+/// Canonical form of an `xsd:dateTime`: fractional seconds without trailing
+/// zeros, and `Z` for the UTC timezone.
+///
+/// Timezone offsets other than UTC are preserved.
+fn canonical_date_time(value: &str) -> Option<String> {
+	let (date, time, timezone) = split_date_time(value)?;
+
+	let time = match time.split_once('.') {
+		Some((hms, fraction)) => match fraction.trim_end_matches('0') {
+			"" => hms.to_owned(),
+			fraction => format!("{hms}.{fraction}"),
+		},
+		None => time.to_owned(),
+	};
+
+	let timezone = match timezone {
+		"+00:00" | "-00:00" => "Z",
+		timezone => timezone,
+	};
+
+	Some(format!("{date}T{time}{timezone}"))
+}
+
+/// Fields of a valid `xsd:dateTime`.
+struct DateTime<'a> {
+	/// Days since the Unix epoch.
+	days: i64,
+
+	/// Minutes since the start of the day.
+	minutes: i64,
+
+	/// Seconds, with their optional fractional part.
+	seconds: &'a str,
+
+	/// Timezone offset in minutes, if any.
+	offset: Option<i64>,
+}
+
+impl<'a> DateTime<'a> {
+	fn parse(value: &'a str) -> Option<Self> {
+		let (date, time, timezone) = split_date_time(value)?;
+
+		let (negative, date) = sign(date);
+		let mut date_parts = date.split('-');
+		let year: i64 = date_parts.next()?.parse().ok()?;
+		let month: i64 = date_parts.next()?.parse().ok()?;
+		let day: i64 = date_parts.next()?.parse().ok()?;
+		let year = if negative { -year } else { year };
+
+		let mut time_parts = time.split(':');
+		let hours: i64 = time_parts.next()?.parse().ok()?;
+		let minutes: i64 = time_parts.next()?.parse().ok()?;
+		let seconds = time_parts.next()?;
+
+		let offset = match timezone {
+			"" => None,
+			"Z" => Some(0),
+			_ => {
+				let (negative, offset) = sign(timezone);
+				let (h, m) = offset.split_once(':')?;
+				let offset = h.parse::<i64>().ok()? * 60 + m.parse::<i64>().ok()?;
+				Some(if negative { -offset } else { offset })
+			}
+		};
+
+		Some(Self {
+			days: days_from_civil(year, month, day),
+			minutes: hours * 60 + minutes,
+			seconds,
+			offset,
+		})
+	}
+}
+
+/// Parses a valid `xsd:dateTime` into a number of seconds since the Unix
+/// epoch.
+///
+/// Values without timezone are assumed to be in UTC.
+pub(crate) fn parse_date_time(value: &str) -> Option<f64> {
+	let date_time = DateTime::parse(value)?;
+	let seconds: f64 = date_time.seconds.parse().ok()?;
+	let minutes = date_time.days * 1440 + date_time.minutes - date_time.offset.unwrap_or(0);
+	Some(minutes as f64 * 60.0 + seconds)
+}
+
+/// Canonical form of an `xsd:dateTime` converted to UTC.
+///
+/// Values with a timezone offset are shifted to the equivalent UTC time, so
+/// that values denoting the same instant have the same form. Values without
+/// timezone are left in their canonical form.
+pub(crate) fn utc_date_time(value: &str) -> Option<String> {
+	let date_time = DateTime::parse(value)?;
+	let Some(offset) = date_time.offset else {
+		return canonical_date_time(value);
+	};
+
+	let minutes = date_time.days * 1440 + date_time.minutes - offset;
+	let (year, month, day) = civil_from_days(minutes.div_euclid(1440));
+	let minutes = minutes.rem_euclid(1440);
+
+	let seconds = match date_time.seconds.split_once('.') {
+		Some((seconds, fraction)) => match fraction.trim_end_matches('0') {
+			"" => seconds.to_owned(),
+			fraction => format!("{seconds}.{fraction}"),
+		},
+		None => date_time.seconds.to_owned(),
+	};
+
+	let year = if year < 0 {
+		format!("-{:04}", -year)
+	} else {
+		format!("{year:04}")
+	};
+
+	Some(format!(
+		"{year}-{month:02}-{day:02}T{:02}:{:02}:{seconds}Z",
+		minutes / 60,
+		minutes % 60
+	))
+}
+
+/// Number of days between the Unix epoch and the given proleptic Gregorian
+/// date.
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+	let year = if month <= 2 { year - 1 } else { year };
+	let era = year.div_euclid(400);
+	let year_of_era = year.rem_euclid(400);
+	let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+	let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+	era * 146097 + day_of_era - 719468
+}
+
+/// Proleptic Gregorian date at the given number of days since the Unix
+/// epoch.
+///
+/// Inverse of [`days_from_civil`].
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+	let days = days + 719468;
+	let era = days.div_euclid(146097);
+	let day_of_era = days.rem_euclid(146097);
+	let year_of_era =
+		(day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+	let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+	let month = (5 * day_of_year + 2) / 153;
+	let day = day_of_year - (153 * month + 2) / 5 + 1;
+	let month = if month < 10 { month + 3 } else { month - 9 };
+	let year = year_of_era + era * 400;
+	(if month <= 2 { year + 1 } else { year }, month, day)
+}
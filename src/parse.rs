@@ -0,0 +1,208 @@
+//! Parsing of N-Triples and N-Quads statements.
+use iref::IriBuf;
+use langtag::LangTagBuf;
+
+use crate::{BlankIdBuf, Direction, Id, InvalidLiteral, Literal, LiteralType, Term, XSD_STRING};
+
+/// N-Triples/N-Quads statement parser.
+///
+/// Every parsing method returns the byte offset at which the input is invalid
+/// in case of error.
+pub(crate) struct Parser<'a> {
+	input: &'a str,
+	offset: usize,
+}
+
+impl<'a> Parser<'a> {
+	pub fn new(input: &'a str) -> Self {
+		Self { input, offset: 0 }
+	}
+
+	fn rest(&self) -> &'a str {
+		&self.input[self.offset..]
+	}
+
+	fn peek(&self) -> Option<char> {
+		self.rest().chars().next()
+	}
+
+	fn next_char(&mut self) -> Option<char> {
+		let c = self.peek()?;
+		self.offset += c.len_utf8();
+		Some(c)
+	}
+
+	fn expect(&mut self, c: char) -> Result<(), usize> {
+		if self.peek() == Some(c) {
+			self.offset += c.len_utf8();
+			Ok(())
+		} else {
+			Err(self.offset)
+		}
+	}
+
+	pub fn skip_whitespace(&mut self) {
+		while let Some(' ' | '\t') = self.peek() {
+			self.offset += 1
+		}
+	}
+
+	/// Checks if the next token is the final `.` of a statement.
+	pub fn is_end(&self) -> bool {
+		self.peek() == Some('.')
+	}
+
+	/// Checks that the whole input has been consumed.
+	pub fn finish(&self) -> Result<(), usize> {
+		if self.rest().is_empty() {
+			Ok(())
+		} else {
+			Err(self.offset)
+		}
+	}
+
+	/// Parses the final `.` of a statement, followed by an optional comment.
+	pub fn end(&mut self) -> Result<(), usize> {
+		self.expect('.')?;
+		self.skip_whitespace();
+		match self.peek() {
+			None | Some('#') => Ok(()),
+			Some(_) if self.rest().trim().is_empty() => Ok(()),
+			Some(_) => Err(self.offset),
+		}
+	}
+
+	/// Parses a unicode escape sequence (`\uXXXX` or `\UXXXXXXXX`), the
+	/// leading `\` being already consumed.
+	fn unicode_escape(&mut self, len: usize) -> Result<char, usize> {
+		let start = self.offset;
+		let digits = self.rest().get(..len).ok_or(start)?;
+		if !digits.bytes().all(|b| b.is_ascii_hexdigit()) {
+			return Err(start);
+		}
+
+		let c = u32::from_str_radix(digits, 16)
+			.ok()
+			.and_then(char::from_u32)
+			.ok_or(start)?;
+		self.offset += len;
+		Ok(c)
+	}
+
+	/// Parses an IRI between angle brackets.
+	pub fn iri(&mut self) -> Result<IriBuf, usize> {
+		let start = self.offset;
+		self.expect('<')?;
+		let mut value = String::new();
+		loop {
+			match self.next_char().ok_or(start)? {
+				'>' => break,
+				'\\' => match self.next_char().ok_or(start)? {
+					'u' => value.push(self.unicode_escape(4)?),
+					'U' => value.push(self.unicode_escape(8)?),
+					c => return Err(self.offset - c.len_utf8()),
+				},
+				c => value.push(c),
+			}
+		}
+
+		IriBuf::new(value).map_err(|_| start)
+	}
+
+	/// Parses a blank node identifier.
+	pub fn blank_id(&mut self) -> Result<BlankIdBuf, usize> {
+		let start = self.offset;
+		self.expect('_')?;
+		self.expect(':')?;
+
+		let len = self
+			.rest()
+			.find(|c: char| !(c.is_alphanumeric() || matches!(c, '_' | '-' | '.') || c > '\x7f'))
+			.unwrap_or(self.rest().len());
+		let len = self.rest()[..len].trim_end_matches('.').len();
+		self.offset += len;
+
+		BlankIdBuf::new(self.input[start..self.offset].to_owned()).map_err(|_| start)
+	}
+
+	/// Parses a node identifier.
+	pub fn id(&mut self) -> Result<Id, usize> {
+		match self.peek() {
+			Some('<') => self.iri().map(Id::Iri),
+			Some('_') => self.blank_id().map(Id::Blank),
+			_ => Err(self.offset),
+		}
+	}
+
+	/// Parses a literal value, with its optional datatype or language tag.
+	pub fn literal(&mut self) -> Result<Literal, InvalidLiteral> {
+		let start = self.offset;
+		self.expect('"').map_err(InvalidLiteral::Expected)?;
+		let mut value = String::new();
+		loop {
+			match self
+				.next_char()
+				.ok_or(InvalidLiteral::Unterminated(start))?
+			{
+				'"' => break,
+				'\\' => match self
+					.next_char()
+					.ok_or(InvalidLiteral::Unterminated(start))?
+				{
+					't' => value.push('\t'),
+					'b' => value.push('\x08'),
+					'n' => value.push('\n'),
+					'r' => value.push('\r'),
+					'f' => value.push('\x0c'),
+					'"' => value.push('"'),
+					'\'' => value.push('\''),
+					'\\' => value.push('\\'),
+					'u' => value.push(self.unicode_escape(4).map_err(InvalidLiteral::Escape)?),
+					'U' => value.push(self.unicode_escape(8).map_err(InvalidLiteral::Escape)?),
+					c => return Err(InvalidLiteral::Escape(self.offset - c.len_utf8())),
+				},
+				c => value.push(c),
+			}
+		}
+
+		let type_ = match self.peek() {
+			Some('^') => {
+				self.expect('^').map_err(InvalidLiteral::Datatype)?;
+				self.expect('^').map_err(InvalidLiteral::Datatype)?;
+				LiteralType::Any(self.iri().map_err(InvalidLiteral::Datatype)?)
+			}
+			Some('@') => {
+				self.offset += 1;
+				let tag_start = self.offset;
+				let len = self
+					.rest()
+					.find(|c: char| !(c.is_ascii_alphanumeric() || c == '-'))
+					.unwrap_or(self.rest().len());
+				self.offset += len;
+				let (tag, direction) = match self.input[tag_start..self.offset].split_once("--") {
+					Some((tag, "ltr")) => (tag, Some(Direction::Ltr)),
+					Some((tag, "rtl")) => (tag, Some(Direction::Rtl)),
+					Some(_) => return Err(InvalidLiteral::LanguageTag(tag_start)),
+					None => (&self.input[tag_start..self.offset], None),
+				};
+				let tag = LangTagBuf::new(tag.to_owned())
+					.map_err(|_| InvalidLiteral::LanguageTag(tag_start))?;
+				match direction {
+					Some(direction) => LiteralType::DirLangString(tag, direction),
+					None => LiteralType::LangString(tag),
+				}
+			}
+			_ => LiteralType::Any(XSD_STRING.to_owned()),
+		};
+
+		Ok(Literal::new(value, type_))
+	}
+
+	/// Parses a term.
+	pub fn term(&mut self) -> Result<Term, usize> {
+		match self.peek() {
+			Some('"') => self.literal().map(Term::Literal).map_err(|e| e.offset()),
+			_ => self.id().map(Term::Id),
+		}
+	}
+}
@@ -0,0 +1,457 @@
+use std::collections::BTreeMap;
+use std::fmt;
+
+use iref::{Iri, IriBuf};
+
+use crate::blankid::{is_pn_char, is_pn_char_base, is_pn_char_u};
+use crate::{
+	BlankId, BlankIdBuf, Id, IsXsdStringIri, Literal, LiteralType, Quad, RdfDisplay, Term, Triple,
+};
+
+/// Invalid prefix name.
+///
+/// This error is raised by the [`PrefixMap::insert`] function when the given
+/// prefix is not a valid `PN_PREFIX`.
+#[derive(Debug, thiserror::Error)]
+#[error("invalid prefix name `{0}`")]
+pub struct InvalidPrefix<T>(pub T);
+
+/// Prefix map, associating prefix names to namespace IRIs.
+///
+/// Used to display IRIs as prefixed names (`prefix:local`) through the
+/// [`PrefixedDisplay`] trait.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct PrefixMap {
+	prefixes: BTreeMap<String, IriBuf>,
+}
+
+impl PrefixMap {
+	/// Creates a new empty prefix map.
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Returns the number of prefixes in the map.
+	pub fn len(&self) -> usize {
+		self.prefixes.len()
+	}
+
+	/// Checks if the map is empty.
+	pub fn is_empty(&self) -> bool {
+		self.prefixes.is_empty()
+	}
+
+	/// Returns the namespace associated to the given prefix, if any.
+	pub fn get(&self, prefix: &str) -> Option<&Iri> {
+		self.prefixes.get(prefix).map(IriBuf::as_iri)
+	}
+
+	/// Associates the given prefix to the given namespace.
+	///
+	/// Returns the namespace previously associated to the prefix, if any, or
+	/// an error if the prefix is not a valid `PN_PREFIX`. The empty prefix is
+	/// allowed.
+	pub fn insert(
+		&mut self,
+		prefix: impl Into<String>,
+		namespace: IriBuf,
+	) -> Result<Option<IriBuf>, InvalidPrefix<String>> {
+		let prefix = prefix.into();
+		if is_pn_prefix(&prefix) {
+			Ok(self.prefixes.insert(prefix, namespace))
+		} else {
+			Err(InvalidPrefix(prefix))
+		}
+	}
+
+	/// Removes the given prefix from the map, returning its namespace.
+	pub fn remove(&mut self, prefix: &str) -> Option<IriBuf> {
+		self.prefixes.remove(prefix)
+	}
+
+	/// Returns an iterator over the prefixes and their namespace, ordered by
+	/// prefix.
+	pub fn iter(&self) -> impl Iterator<Item = (&str, &Iri)> {
+		self.prefixes
+			.iter()
+			.map(|(prefix, namespace)| (prefix.as_str(), namespace.as_iri()))
+	}
+
+	/// Splits the given IRI into a prefix and local part, if possible.
+	///
+	/// Among the namespaces the IRI starts with, the longest one for which
+	/// the rest of the IRI is a valid `PN_LOCAL` is selected.
+	pub fn compact<'a>(&'a self, iri: &'a Iri) -> Option<(&'a str, &'a str)> {
+		let mut result: Option<(&str, &str)> = None;
+
+		for (prefix, namespace) in &self.prefixes {
+			if let Some(local) = iri.as_str().strip_prefix(namespace.as_str()) {
+				if is_pn_local(local) && result.map_or(true, |(_, l)| local.len() < l.len()) {
+					result = Some((prefix, local))
+				}
+			}
+		}
+
+		result
+	}
+}
+
+impl<'a> IntoIterator for &'a PrefixMap {
+	type Item = (&'a String, &'a IriBuf);
+	type IntoIter = std::collections::btree_map::Iter<'a, String, IriBuf>;
+
+	fn into_iter(self) -> Self::IntoIter {
+		self.prefixes.iter()
+	}
+}
+
+/// Checks that the given string matches the `PN_PREFIX` production, or is
+/// empty.
+///
+/// ```ebnf
+/// [167s] PN_PREFIX ::= PN_CHARS_BASE ((PN_CHARS | '.')* PN_CHARS)?
+/// ```
+fn is_pn_prefix(prefix: &str) -> bool {
+	let mut chars = prefix.chars();
+	match chars.next() {
+		Some(c) if is_pn_char_base(c) => {
+			!prefix.ends_with('.') && chars.all(|c| c != ':' && (is_pn_char(c) || c == '.'))
+		}
+		Some(_) => false,
+		None => true,
+	}
+}
+
+/// Checks that the given string matches the `PN_LOCAL` production, without
+/// any `PN_LOCAL_ESC` escape sequence, or is empty.
+///
+/// ```ebnf
+/// [168s] PN_LOCAL ::= (PN_CHARS_U | ':' | [0-9] | PLX) ((PN_CHARS | '.' | ':' | PLX)* (PN_CHARS | ':' | PLX))?
+/// [170s] PERCENT  ::= '%' HEX HEX
+/// ```
+fn is_pn_local(local: &str) -> bool {
+	let mut chars = local.chars();
+	let mut first = true;
+
+	while let Some(c) = chars.next() {
+		let valid = match c {
+			'%' => {
+				let mut hex = chars.by_ref().take(2).filter(char::is_ascii_hexdigit);
+				hex.next().is_some() && hex.next().is_some()
+			}
+			'.' => !first,
+			c => is_pn_char_u(c) || c.is_ascii_digit() || (!first && is_pn_char(c)),
+		};
+
+		if !valid {
+			return false;
+		}
+
+		first = false
+	}
+
+	!local.ends_with('.')
+}
+
+/// Display method for RDF syntax elements, using prefixed names when
+/// possible.
+pub trait PrefixedDisplay {
+	/// Formats the value using the given prefix map and formatter.
+	fn prefixed_fmt(&self, prefixes: &PrefixMap, f: &mut fmt::Formatter) -> fmt::Result;
+
+	/// Prepare the value to be formatted using the given prefix map.
+	///
+	/// IRIs starting with a known namespace are displayed as
+	/// `prefix:local`, other IRIs are displayed between angle brackets.
+	#[inline(always)]
+	fn with_prefixes<'a>(&'a self, prefixes: &'a PrefixMap) -> WithPrefixes<'a, Self> {
+		WithPrefixes(self, prefixes)
+	}
+}
+
+/// Value ready to be formatted using a prefix map.
+pub struct WithPrefixes<'a, T: ?Sized>(&'a T, &'a PrefixMap);
+
+impl<'a, T: PrefixedDisplay + ?Sized> fmt::Display for WithPrefixes<'a, T> {
+	#[inline(always)]
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		self.0.prefixed_fmt(self.1, f)
+	}
+}
+
+impl<'a, T: PrefixedDisplay + ?Sized> PrefixedDisplay for &'a T {
+	#[inline(always)]
+	fn prefixed_fmt(&self, prefixes: &PrefixMap, f: &mut fmt::Formatter) -> fmt::Result {
+		T::prefixed_fmt(*self, prefixes, f)
+	}
+}
+
+impl PrefixedDisplay for Iri {
+	fn prefixed_fmt(&self, prefixes: &PrefixMap, f: &mut fmt::Formatter) -> fmt::Result {
+		match prefixes.compact(self) {
+			Some((prefix, local)) => write!(f, "{prefix}:{local}"),
+			None => self.rdf_fmt(f),
+		}
+	}
+}
+
+impl PrefixedDisplay for IriBuf {
+	#[inline(always)]
+	fn prefixed_fmt(&self, prefixes: &PrefixMap, f: &mut fmt::Formatter) -> fmt::Result {
+		self.as_iri().prefixed_fmt(prefixes, f)
+	}
+}
+
+impl PrefixedDisplay for BlankId {
+	fn prefixed_fmt(&self, _prefixes: &PrefixMap, f: &mut fmt::Formatter) -> fmt::Result {
+		fmt::Display::fmt(self, f)
+	}
+}
+
+impl PrefixedDisplay for BlankIdBuf {
+	fn prefixed_fmt(&self, _prefixes: &PrefixMap, f: &mut fmt::Formatter) -> fmt::Result {
+		fmt::Display::fmt(self, f)
+	}
+}
+
+impl<I: PrefixedDisplay> PrefixedDisplay for LiteralType<I> {
+	fn prefixed_fmt(&self, prefixes: &PrefixMap, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			Self::Any(ty) => {
+				write!(f, "^^")?;
+				ty.prefixed_fmt(prefixes, f)
+			}
+			Self::LangString(tag) => {
+				write!(f, "@")?;
+				tag.rdf_fmt(f)
+			}
+			Self::DirLangString(tag, direction) => {
+				write!(f, "@")?;
+				tag.rdf_fmt(f)?;
+				write!(f, "--{direction}")
+			}
+		}
+	}
+}
+
+impl<I: PrefixedDisplay + IsXsdStringIri, S: RdfDisplay> PrefixedDisplay for Literal<I, S> {
+	fn prefixed_fmt(&self, prefixes: &PrefixMap, f: &mut fmt::Formatter) -> fmt::Result {
+		self.value.rdf_fmt(f)?;
+		if self.type_.is_xsd_string() {
+			Ok(())
+		} else {
+			self.type_.prefixed_fmt(prefixes, f)
+		}
+	}
+}
+
+impl<I: PrefixedDisplay, B: PrefixedDisplay> PrefixedDisplay for Id<I, B> {
+	fn prefixed_fmt(&self, prefixes: &PrefixMap, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			Self::Iri(iri) => iri.prefixed_fmt(prefixes, f),
+			Self::Blank(id) => id.prefixed_fmt(prefixes, f),
+		}
+	}
+}
+
+impl<I: PrefixedDisplay, L: PrefixedDisplay> PrefixedDisplay for Term<I, L> {
+	fn prefixed_fmt(&self, prefixes: &PrefixMap, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			Self::Id(id) => id.prefixed_fmt(prefixes, f),
+			Self::Literal(lit) => lit.prefixed_fmt(prefixes, f),
+		}
+	}
+}
+
+impl<S: PrefixedDisplay, P: PrefixedDisplay, O: PrefixedDisplay> PrefixedDisplay
+	for Triple<S, P, O>
+{
+	fn prefixed_fmt(&self, prefixes: &PrefixMap, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(
+			f,
+			"{} {} {}",
+			self.0.with_prefixes(prefixes),
+			self.1.with_prefixes(prefixes),
+			self.2.with_prefixes(prefixes)
+		)
+	}
+}
+
+impl<S: PrefixedDisplay, P: PrefixedDisplay, O: PrefixedDisplay, G: PrefixedDisplay> PrefixedDisplay
+	for Quad<S, P, O, G>
+{
+	fn prefixed_fmt(&self, prefixes: &PrefixMap, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(
+			f,
+			"{} {} {}",
+			self.0.with_prefixes(prefixes),
+			self.1.with_prefixes(prefixes),
+			self.2.with_prefixes(prefixes)
+		)?;
+
+		match self.graph() {
+			Some(graph) => write!(f, " {}", graph.with_prefixes(prefixes)),
+			None => Ok(()),
+		}
+	}
+}
+
+/// Turtle-like display of an RDF syntax element, using a prefix map.
+///
+/// Terms are displayed using prefixed names when possible, and triples and
+/// quads are displayed as a single statement terminated by ` .`. Unlike
+/// `DisplayWithContext`, this does not require the `contextual` feature.
+///
+/// Quads are displayed N-Quads-like, with the graph label after the object
+/// (`s p o g .`). This is neither valid Turtle nor TriG.
+///
+/// ```
+/// use rdf_types::{Id, PrefixMap, Turtle, LexicalTriple, Term, Triple};
+/// use static_iref::iri;
+///
+/// let mut prefixes = PrefixMap::new();
+/// prefixes.insert("ex", iri!("http://example.org/").to_owned()).unwrap();
+///
+/// let triple: LexicalTriple = Triple(
+///   Id::Iri(iri!("http://example.org/a").to_owned()),
+///   iri!("http://example.org/p").to_owned(),
+///   Term::Id(Id::Iri(iri!("http://other.org/b").to_owned()))
+/// );
+///
+/// assert_eq!(
+///   Turtle(&triple, &prefixes).to_string(),
+///   "ex:a ex:p <http://other.org/b> ."
+/// )
+/// ```
+pub struct Turtle<'a, T: ?Sized>(pub &'a T, pub &'a PrefixMap);
+
+impl<'a, I: PrefixedDisplay, L: PrefixedDisplay> fmt::Display for Turtle<'a, Term<I, L>> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		self.0.prefixed_fmt(self.1, f)
+	}
+}
+
+impl<'a, S: PrefixedDisplay, P: PrefixedDisplay, O: PrefixedDisplay> fmt::Display
+	for Turtle<'a, Triple<S, P, O>>
+{
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "{} .", self.0.with_prefixes(self.1))
+	}
+}
+
+impl<'a, S: PrefixedDisplay, P: PrefixedDisplay, O: PrefixedDisplay, G: PrefixedDisplay>
+	fmt::Display for Turtle<'a, Quad<S, P, O, G>>
+{
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "{} .", self.0.with_prefixes(self.1))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{LexicalQuad, LexicalTriple, XSD_INTEGER, XSD_STRING};
+	use static_iref::iri;
+
+	fn prefixes() -> PrefixMap {
+		let mut prefixes = PrefixMap::new();
+		prefixes
+			.insert("ex", iri!("http://example.org/").to_owned())
+			.unwrap();
+		prefixes
+			.insert("exa", iri!("http://example.org/a").to_owned())
+			.unwrap();
+		prefixes
+			.insert("xsd", iri!("http://www.w3.org/2001/XMLSchema#").to_owned())
+			.unwrap();
+		prefixes
+	}
+
+	#[test]
+	fn compact() {
+		let prefixes = prefixes();
+		let id = |iri: &Iri| Id::<IriBuf, BlankIdBuf>::Iri(iri.to_owned());
+
+		assert_eq!(
+			id(iri!("http://example.org/abc"))
+				.with_prefixes(&prefixes)
+				.to_string(),
+			"exa:bc"
+		);
+		assert_eq!(
+			id(iri!("http://example.org/b.c%20d"))
+				.with_prefixes(&prefixes)
+				.to_string(),
+			"ex:b.c%20d"
+		);
+		assert_eq!(
+			id(iri!("http://example.org/a/b"))
+				.with_prefixes(&prefixes)
+				.to_string(),
+			"<http://example.org/a/b>"
+		);
+		assert_eq!(
+			id(iri!("http://example.org/b."))
+				.with_prefixes(&prefixes)
+				.to_string(),
+			"<http://example.org/b.>"
+		);
+		assert_eq!(
+			id(iri!("http://other.org/#a"))
+				.with_prefixes(&prefixes)
+				.to_string(),
+			"<http://other.org/#a>"
+		)
+	}
+
+	#[test]
+	fn triple() {
+		let triple: LexicalTriple = Triple(
+			Id::Blank(BlankIdBuf::from_suffix("b0").unwrap()),
+			iri!("http://example.org/p").to_owned(),
+			Term::Literal(Literal::new(
+				"42".to_owned(),
+				LiteralType::Any(XSD_INTEGER.to_owned()),
+			)),
+		);
+
+		assert_eq!(
+			triple.with_prefixes(&prefixes()).to_string(),
+			"_:b0 ex:p \"42\"^^xsd:integer"
+		)
+	}
+
+	#[test]
+	fn turtle() {
+		let prefixes = prefixes();
+		let quad: LexicalQuad = Quad(
+			Id::Iri(iri!("http://example.org/s").to_owned()),
+			iri!("http://example.org/p").to_owned(),
+			Term::Literal(Literal::new(
+				"v".to_owned(),
+				LiteralType::Any(XSD_STRING.to_owned()),
+			)),
+			Some(Id::Iri(iri!("http://example.org/g").to_owned())),
+		);
+
+		assert_eq!(
+			Turtle(&quad, &prefixes).to_string(),
+			"ex:s ex:p \"v\" ex:g ."
+		);
+		assert_eq!(
+			Turtle(&quad.into_triple().0, &prefixes).to_string(),
+			"ex:s ex:p \"v\" ."
+		)
+	}
+
+	#[test]
+	fn invalid_prefix() {
+		let mut prefixes = PrefixMap::new();
+		let namespace = iri!("http://example.org/").to_owned();
+		assert!(prefixes.insert("", namespace.clone()).is_ok());
+		assert!(prefixes.insert("a.b", namespace.clone()).is_ok());
+		assert!(prefixes.insert("a.", namespace.clone()).is_err());
+		assert!(prefixes.insert("0a", namespace.clone()).is_err());
+		assert!(prefixes.insert("a:b", namespace).is_err())
+	}
+}
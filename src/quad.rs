@@ -1,15 +1,23 @@
-use std::{cmp::Ordering, fmt};
+use std::{
+	cmp::Ordering,
+	collections::HashMap,
+	fmt,
+	hash::{Hash, Hasher},
+	str::FromStr,
+};
 
 use iref::{Iri, IriBuf};
 
 use crate::{
 	interpretation::Interpret,
+	parse::Parser,
 	vocabulary::{
 		ByRef, EmbedIntoVocabulary, EmbeddedIntoVocabulary, ExtractFromVocabulary,
 		ExtractedFromVocabulary, TryExtractFromVocabulary,
 	},
-	GraphLabel, Id, Interpretation, LexicalGraphLabelRef, LexicalObjectRef, LexicalSubjectRef,
-	Object, RdfDisplay, Term, Triple,
+	BlankId, GraphLabel, Id, Interpretation, LexicalGraphLabelRef, LexicalObjectRef,
+	LexicalSubjectRef, LexicalTermRef, Literal, LiteralType, LiteralTypeRef, Object, RdfDisplay,
+	Term, Triple, TryExportId, Vocabulary, VocabularyMut,
 };
 
 #[cfg(feature = "contextual")]
@@ -25,7 +33,32 @@ pub type LexicalQuad = Quad<Id, IriBuf, Object, GraphLabel>;
 pub type LexicalQuadRef<'a> =
 	Quad<LexicalSubjectRef<'a>, &'a Iri, LexicalObjectRef<'a>, LexicalGraphLabelRef<'a>>;
 
+/// Quad whose components have been inserted into a vocabulary, using `I`,
+/// `B` and `L` as IRI, blank node identifier and literal types.
+pub type VocabularyQuad<I, B, L> = Quad<Id<I, B>, I, Term<Id<I, B>, L>, Id<I, B>>;
+
+/// Position of a component in a quad.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub enum Position {
+	/// Subject, the first component.
+	Subject,
+
+	/// Predicate, the second component.
+	Predicate,
+
+	/// Object, the third component.
+	Object,
+
+	/// Graph label, the fourth component.
+	Graph,
+}
+
 /// RDF quad.
+///
+/// Quads are ordered lexicographically by subject, predicate, object and
+/// graph, quads in the default graph (without graph label) coming first. The
+/// derived `Ord` implementation and the generic `PartialOrd` implementation
+/// agree on this order.
 #[derive(Clone, Copy, Eq, Ord, Hash, Debug)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Quad<S = Term, P = S, O = S, G = S>(pub S, pub P, pub O, pub Option<G>);
@@ -105,6 +138,135 @@ impl LexicalQuad {
 			self.3.as_ref().map(GraphLabel::as_graph_label_ref),
 		)
 	}
+
+	/// Returns every component of the quad tagged with its position.
+	///
+	/// The graph component is omitted if it is `None`.
+	pub fn position_tagged_terms(&self) -> Vec<(Position, LexicalTermRef)> {
+		let mut result = vec![
+			(Position::Subject, Term::Id(self.0.as_lexical_id_ref())),
+			(Position::Predicate, Term::Id(Id::Iri(self.1.as_iri()))),
+			(Position::Object, self.2.as_lexical_term_ref()),
+		];
+
+		if let Some(g) = &self.3 {
+			result.push((Position::Graph, Term::Id(g.as_lexical_id_ref())))
+		}
+
+		result
+	}
+
+	/// Returns the input of the first degree hash of the given `reference`
+	/// blank node identifier for this quad, as defined by the
+	/// [URDNA2015][1] canonicalization algorithm.
+	///
+	/// This is the N-Quads serialization of the quad (where each term is
+	/// written in its position), where the reference blank node identifier is
+	/// replaced by `_:a` and every other blank node identifier by `_:z`.
+	/// Quads that only differ by the label of their blank nodes hence share
+	/// the same hash input.
+	///
+	/// [1]: <https://www.w3.org/TR/rdf-canon/#hash-1d-quads>
+	pub fn first_degree_hash_input(&self, reference: &BlankId) -> String {
+		use fmt::Write;
+		let mut result = String::new();
+
+		for (_, term) in self.position_tagged_terms() {
+			match term {
+				Term::Id(Id::Blank(b)) if b == reference => result.push_str("_:a"),
+				Term::Id(Id::Blank(_)) => result.push_str("_:z"),
+				term => write!(result, "{}", term.rdf_display()).unwrap(),
+			}
+
+			result.push(' ')
+		}
+
+		result.push_str(".\n");
+		result
+	}
+
+	/// Inserts every component of the quad into the given vocabulary,
+	/// including literal values and their types.
+	pub fn insert_into<V: VocabularyMut>(
+		self,
+		vocabulary: &mut V,
+	) -> VocabularyQuad<V::Iri, V::BlankId, V::Literal> {
+		self.embed_into_vocabulary(vocabulary)
+	}
+
+	/// Inserts a copy of every component of the quad into the given
+	/// vocabulary, including literal values and their types.
+	pub fn inserted_into<V: VocabularyMut>(
+		&self,
+		vocabulary: &mut V,
+	) -> VocabularyQuad<V::Iri, V::BlankId, V::Literal> {
+		self.embedded_into_vocabulary(vocabulary)
+	}
+}
+
+/// Error returned when parsing an N-Quads statement.
+///
+/// Each variant holds the byte offset at which the input is invalid.
+#[derive(Debug, thiserror::Error)]
+pub enum InvalidQuad {
+	#[error("invalid subject at byte offset {0}")]
+	Subject(usize),
+
+	#[error("invalid predicate at byte offset {0}")]
+	Predicate(usize),
+
+	#[error("invalid object at byte offset {0}")]
+	Object(usize),
+
+	#[error("invalid graph label at byte offset {0}")]
+	Graph(usize),
+
+	#[error("expected `.` at byte offset {0}")]
+	End(usize),
+}
+
+impl FromStr for LexicalQuad {
+	type Err = InvalidQuad;
+
+	/// Parses a single N-Quads statement.
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let mut parser = Parser::new(s);
+		parser.skip_whitespace();
+		let subject = parser.id().map_err(InvalidQuad::Subject)?;
+		parser.skip_whitespace();
+		let predicate = parser.iri().map_err(InvalidQuad::Predicate)?;
+		parser.skip_whitespace();
+		let object = parser.term().map_err(InvalidQuad::Object)?;
+		parser.skip_whitespace();
+		let graph = if parser.is_end() {
+			None
+		} else {
+			let graph = parser.id().map_err(InvalidQuad::Graph)?;
+			parser.skip_whitespace();
+			Some(graph)
+		};
+		parser.end().map_err(InvalidQuad::End)?;
+		Ok(Quad(subject, predicate, object, graph))
+	}
+}
+
+impl<I: PartialEq, B, L: PartialEq> Quad<Id<I, B>, I, Term<Id<I, B>, L>, Id<I, B>> {
+	/// Compares the two quads modulo blank node identifiers, considering that
+	/// every blank node identifier is equal to any other blank node
+	/// identifier.
+	///
+	/// This is a cheap approximation of isomorphism: isomorphic quads are
+	/// always ground-equal, but the converse is not true.
+	pub fn ground_eq(&self, other: &Self) -> bool {
+		self.0.ground_eq(&other.0)
+			&& self.1 == other.1
+			&& self.2.ground_eq(&other.2)
+			&& match (&self.3, &other.3) {
+				(Some(a), Some(b)) => a.ground_eq(b),
+				(None, None) => true,
+				_ => false,
+			}
+	}
 }
 
 impl<'a> LexicalQuadRef<'a> {
@@ -164,6 +326,13 @@ impl<S, P, O, G> Quad<S, P, O, G> {
 		Self(subject, predicate, object, graph)
 	}
 
+	/// Creates a new quad from a triple and a `graph` component.
+	///
+	/// This is the inverse of [`Self::into_triple`].
+	pub fn from_triple(triple: Triple<S, P, O>, graph: Option<G>) -> Self {
+		Self(triple.0, triple.1, triple.2, graph)
+	}
+
 	/// Returns a reference to the subject of the quad,
 	/// the first component.
 	pub fn subject(&self) -> &S {
@@ -236,6 +405,12 @@ impl<S, P, O, G> Quad<S, P, O, G> {
 		self.3
 	}
 
+	/// Checks if the quad belongs to the default graph, meaning it has no
+	/// graph component.
+	pub fn is_default_graph(&self) -> bool {
+		self.3.is_none()
+	}
+
 	pub fn into_parts(self) -> (S, P, O, Option<G>) {
 		(self.0, self.1, self.2, self.3)
 	}
@@ -245,6 +420,13 @@ impl<S, P, O, G> Quad<S, P, O, G> {
 		(Triple(self.0, self.1, self.2), self.3)
 	}
 
+	/// Borrows the triple part of the quad, ignoring the graph.
+	///
+	/// Can be used as a key to group quads by triple without cloning them.
+	pub fn triple_key(&self) -> Triple<&S, &P, &O> {
+		Triple(&self.0, &self.1, &self.2)
+	}
+
 	/// Maps the subject with the given function.
 	pub fn map_subject<U>(self, f: impl FnOnce(S) -> U) -> Quad<U, P, O, G> {
 		Quad(f(self.0), self.1, self.2, self.3)
@@ -260,11 +442,31 @@ impl<S, P, O, G> Quad<S, P, O, G> {
 		Quad(self.0, self.1, f(self.2), self.3)
 	}
 
+	/// Swaps the subject and object of the quad, reversing the edge it
+	/// represents while keeping its predicate and graph.
+	///
+	/// Since the subject and object types are swapped, this is mostly useful
+	/// on homogeneous quads such as [`GrdfQuad`](crate::GrdfQuad).
+	pub fn reverse(self) -> Quad<O, P, S, G> {
+		Quad(self.2, self.1, self.0, self.3)
+	}
+
 	/// Maps the graph with the given function.
 	pub fn map_graph<U>(self, f: impl FnOnce(Option<G>) -> Option<U>) -> Quad<S, P, O, U> {
 		Quad(self.0, self.1, self.2, f(self.3))
 	}
 
+	/// Replaces the graph component of the quad.
+	pub fn with_graph(self, graph: Option<G>) -> Self {
+		Quad(self.0, self.1, self.2, graph)
+	}
+
+	/// Moves the quad into the default graph, setting the graph component to
+	/// `None`.
+	pub fn into_default_graph(self) -> Self {
+		self.with_graph(None)
+	}
+
 	/// Maps every quad component with the given functions, one for each
 	/// component.
 	pub fn map_all<S2, P2, O2, G2>(
@@ -283,6 +485,54 @@ impl<T> Quad<T, T, T, T> {
 	pub fn map<U>(self, mut f: impl FnMut(T) -> U) -> Quad<U, U, U, U> {
 		Quad(f(self.0), f(self.1), f(self.2), self.3.map(f))
 	}
+
+	/// Maps the components with the given function, which also receives the
+	/// position of each component.
+	///
+	/// The function is not called for the default graph.
+	pub fn map_indexed<U>(self, mut f: impl FnMut(Position, T) -> U) -> Quad<U, U, U, U> {
+		Quad(
+			f(Position::Subject, self.0),
+			f(Position::Predicate, self.1),
+			f(Position::Object, self.2),
+			self.3.map(|g| f(Position::Graph, g)),
+		)
+	}
+
+	/// Returns an iterator over the components of the quad, including the
+	/// graph if any.
+	pub fn terms(&self) -> QuadComponents<&T> {
+		[&self.0, &self.1, &self.2]
+			.into_iter()
+			.chain(self.3.as_ref())
+	}
+
+	/// Turns the quad into an iterator over its components, including the
+	/// graph if any.
+	pub fn into_terms(self) -> QuadComponents<T> {
+		[self.0, self.1, self.2].into_iter().chain(self.3)
+	}
+}
+
+/// Iterator over the components of a homogeneous quad.
+pub type QuadComponents<T> = std::iter::Chain<std::array::IntoIter<T, 3>, std::option::IntoIter<T>>;
+
+impl<T> IntoIterator for Quad<T, T, T, T> {
+	type Item = T;
+	type IntoIter = QuadComponents<T>;
+
+	fn into_iter(self) -> Self::IntoIter {
+		self.into_terms()
+	}
+}
+
+impl<'a, T> IntoIterator for &'a Quad<T, T, T, T> {
+	type Item = &'a T;
+	type IntoIter = QuadComponents<&'a T>;
+
+	fn into_iter(self) -> Self::IntoIter {
+		self.terms()
+	}
 }
 
 impl<S: Interpret<I>, P: Interpret<I>, O: Interpret<I>, G: Interpret<I>, I: Interpretation>
@@ -371,6 +621,69 @@ pub trait TryExportQuad<S, P, O, G> {
 	fn try_export_quad(&self, quad: Quad<S, P, O, G>) -> Result<LexicalQuad, Self::Error>;
 }
 
+/// Exports quads whose components are stored in the vocabulary.
+///
+/// Fails with the first component that is not bound in the vocabulary,
+/// including literals whose datatype IRI is not bound.
+impl<V: Vocabulary>
+	TryExportQuad<
+		Id<V::Iri, V::BlankId>,
+		V::Iri,
+		Term<Id<V::Iri, V::BlankId>, V::Literal>,
+		Id<V::Iri, V::BlankId>,
+	> for V
+{
+	type Error = QuadExportFailed<
+		Id<V::Iri, V::BlankId>,
+		V::Iri,
+		Term<Id<V::Iri, V::BlankId>, V::Literal>,
+		Id<V::Iri, V::BlankId>,
+	>;
+
+	fn try_export_quad(
+		&self,
+		Quad(s, p, o, g): Quad<
+			Id<V::Iri, V::BlankId>,
+			V::Iri,
+			Term<Id<V::Iri, V::BlankId>, V::Literal>,
+			Id<V::Iri, V::BlankId>,
+		>,
+	) -> Result<LexicalQuad, Self::Error> {
+		let s = self.try_export_id(s).map_err(QuadExportFailed::Subject)?;
+		let p = self.owned_iri(p).map_err(QuadExportFailed::Predicate)?;
+		let o = match o {
+			Term::Id(id) => Term::Id(
+				self.try_export_id(id)
+					.map_err(|id| QuadExportFailed::Object(Term::Id(id)))?,
+			),
+			Term::Literal(l) => match export_literal(self, &l) {
+				Some(l) => Term::Literal(l),
+				None => return Err(QuadExportFailed::Object(Term::Literal(l))),
+			},
+		};
+		let g = g
+			.map(|g| self.try_export_id(g))
+			.transpose()
+			.map_err(QuadExportFailed::Graph)?;
+
+		Ok(Quad(s, p, o, g))
+	}
+}
+
+/// Exports the given literal, including its datatype IRI.
+fn export_literal<V: Vocabulary>(vocabulary: &V, id: &V::Literal) -> Option<Literal> {
+	let literal = vocabulary.literal(id)?;
+	let type_ = match literal.type_ {
+		LiteralTypeRef::Any(ty) => LiteralType::Any(vocabulary.iri(ty)?.to_owned()),
+		LiteralTypeRef::LangString(tag) => LiteralType::LangString(tag.to_owned()),
+		LiteralTypeRef::DirLangString(tag, direction) => {
+			LiteralType::DirLangString(tag.to_owned(), direction)
+		}
+	};
+
+	Some(Literal::new(literal.value.to_owned(), type_))
+}
+
 /// Error returned when calling [`try_extract_from_vocabulary`][1] on a
 /// [`Quad`].
 ///
@@ -472,6 +785,53 @@ impl<
 	}
 }
 
+/// Quad compared and hashed by its triple only.
+///
+/// The graph is ignored by the `PartialEq`, `Eq` and `Hash` implementations
+/// of this wrapper, so that quads with the same subject, predicate and object
+/// are considered equal. See [`Quad::triple_key`].
+#[derive(Clone, Copy, Debug)]
+pub struct QuadByTriple<S, P = S, O = S, G = S>(pub Quad<S, P, O, G>);
+
+impl<S, P, O, G> QuadByTriple<S, P, O, G> {
+	/// Returns the wrapped quad.
+	pub fn into_inner(self) -> Quad<S, P, O, G> {
+		self.0
+	}
+}
+
+impl<S: PartialEq, P: PartialEq, O: PartialEq, G> PartialEq for QuadByTriple<S, P, O, G> {
+	fn eq(&self, other: &Self) -> bool {
+		self.0.triple_key() == other.0.triple_key()
+	}
+}
+
+impl<S: Eq, P: Eq, O: Eq, G> Eq for QuadByTriple<S, P, O, G> {}
+
+impl<S: Hash, P: Hash, O: Hash, G> Hash for QuadByTriple<S, P, O, G> {
+	fn hash<H: Hasher>(&self, state: &mut H) {
+		self.0.triple_key().hash(state)
+	}
+}
+
+/// Partitions the given quads by graph.
+///
+/// Each quad is turned into a triple, stored under its graph component. The
+/// default graph is stored under the `None` key. Triples keep the order in
+/// which they appear in the input.
+pub fn group_by_graph<S, P, O, G: Eq + Hash>(
+	quads: impl IntoIterator<Item = Quad<S, P, O, G>>,
+) -> HashMap<Option<G>, Vec<Triple<S, P, O>>> {
+	let mut graphs: HashMap<_, Vec<_>> = HashMap::new();
+
+	for quad in quads {
+		let (triple, graph) = quad.into_triple();
+		graphs.entry(graph).or_default().push(triple)
+	}
+
+	graphs
+}
+
 impl<S: RdfDisplay, P: RdfDisplay, O: RdfDisplay, G: RdfDisplay> fmt::Display for Quad<S, P, O, G> {
 	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
 		match self.graph() {
@@ -575,3 +935,318 @@ impl<
 		}
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		vocabulary::{
+			BlankIdVocabularyMut, IndexVocabulary, IriVocabulary, LiteralVocabulary, Predicate,
+		},
+		BlankIdBuf,
+	};
+	use static_iref::iri;
+
+	#[test]
+	fn reverse() {
+		let quad: Quad<u32> = Quad(0, 1, 2, Some(3));
+		assert_eq!(quad.reverse(), Quad(2, 1, 0, Some(3)));
+		assert_eq!(quad.into_triple().0.reverse(), Triple(2, 1, 0));
+		assert_eq!(quad.reverse().reverse(), quad)
+	}
+
+	#[test]
+	fn ord_consistency() {
+		let quads: [Quad<u32>; 6] = [
+			Quad(0, 1, 2, None),
+			Quad(0, 1, 2, Some(0)),
+			Quad(0, 1, 2, Some(3)),
+			Quad(0, 1, 3, None),
+			Quad(0, 2, 0, Some(0)),
+			Quad(1, 0, 0, None),
+		];
+
+		for a in &quads {
+			for b in &quads {
+				assert_eq!(a.partial_cmp(b), Some(a.cmp(b)));
+				assert_eq!(a == b, a.cmp(b).is_eq())
+			}
+		}
+
+		let mut sorted = quads;
+		sorted.reverse();
+		sorted.sort();
+		assert_eq!(sorted, quads)
+	}
+
+	#[test]
+	fn components() {
+		let quad: Quad<u32> = Quad(0, 1, 2, Some(3));
+		assert_eq!(quad.terms().copied().collect::<Vec<_>>(), [0, 1, 2, 3]);
+		assert_eq!(
+			Quad(0, 1, 2, None).into_terms().collect::<Vec<_>>(),
+			[0, 1, 2]
+		);
+
+		let mut sum = 0;
+		for c in &quad {
+			sum += c
+		}
+		assert_eq!(sum, 6)
+	}
+
+	#[test]
+	fn group_by_triple() {
+		use std::collections::HashSet;
+
+		let a: Quad<u32> = Quad(0, 1, 2, Some(3));
+		let b = Quad(0, 1, 2, None);
+		assert_eq!(a.triple_key(), b.triple_key());
+		assert_eq!(QuadByTriple(a), QuadByTriple(b));
+		assert_ne!(QuadByTriple(a), QuadByTriple(Quad(0, 1, 4, Some(3))));
+
+		let set: HashSet<_> = [a, b].into_iter().map(QuadByTriple).collect();
+		assert_eq!(set.len(), 1)
+	}
+
+	#[test]
+	fn insert_lexical_quad() {
+		let xsd_integer = iri!("http://www.w3.org/2001/XMLSchema#integer");
+		let quad: LexicalQuad = Quad(
+			Id::Blank(BlankIdBuf::from_suffix("0").unwrap()),
+			iri!("http://example.org/#p").to_owned(),
+			Term::Literal(Literal::new(
+				"42".to_owned(),
+				LiteralType::Any(xsd_integer.to_owned()),
+			)),
+			Some(Id::Iri(iri!("http://example.org/#g").to_owned())),
+		);
+
+		let mut vocabulary: IndexVocabulary = IndexVocabulary::new();
+		let inserted = quad.inserted_into(&mut vocabulary);
+		assert_eq!(inserted, quad.clone().insert_into(&mut vocabulary));
+
+		let literal = vocabulary
+			.literal(inserted.object().as_literal().unwrap())
+			.unwrap();
+		assert_eq!(literal.value, "42");
+		match literal.type_ {
+			LiteralTypeRef::Any(ty) => assert_eq!(vocabulary.iri(ty).unwrap(), xsd_integer),
+			_ => panic!("expected a typed literal"),
+		}
+
+		let extracted: LexicalQuad = inserted
+			.map_predicate(Predicate)
+			.extract_from_vocabulary(&vocabulary);
+		assert_eq!(extracted, quad)
+	}
+
+	#[test]
+	fn first_degree_hash_input() {
+		let a: LexicalQuad = "_:a0 <http://example.org/#p> _:a1 _:a2 .".parse().unwrap();
+		let b: LexicalQuad = "_:b0 <http://example.org/#p> _:b1 _:b2 .".parse().unwrap();
+		let reference_a = BlankIdBuf::from_suffix("a0").unwrap();
+		let reference_b = BlankIdBuf::from_suffix("b0").unwrap();
+
+		let input = a.first_degree_hash_input(&reference_a);
+		assert_eq!(input, "_:a <http://example.org/#p> _:z _:z .\n");
+		assert_eq!(input, b.first_degree_hash_input(&reference_b));
+		assert_ne!(input, b.first_degree_hash_input(&reference_a));
+
+		let positions: Vec<_> = a
+			.position_tagged_terms()
+			.into_iter()
+			.map(|(p, _)| p)
+			.collect();
+		assert_eq!(
+			positions,
+			[
+				Position::Subject,
+				Position::Predicate,
+				Position::Object,
+				Position::Graph
+			]
+		)
+	}
+
+	#[test]
+	fn map_indexed() {
+		let quad = Quad("s", "p", "o", Some("g"));
+		let mapped = quad.map_indexed(|position, t| match position {
+			Position::Predicate => t.to_uppercase(),
+			_ => t.to_owned(),
+		});
+		assert_eq!(mapped, Quad("s", "P", "o", Some("g")).map(str::to_owned));
+
+		let mut positions = Vec::new();
+		Quad(0, 1, 2, None).map_indexed(|position, _| positions.push(position));
+		assert_eq!(
+			positions,
+			[Position::Subject, Position::Predicate, Position::Object]
+		)
+	}
+
+	#[test]
+	fn move_graph() {
+		let quad: LexicalQuad = "_:b0 <http://example.org/#p> _:b1 <http://example.org/#g> ."
+			.parse()
+			.unwrap();
+
+		let moved = quad.clone().into_default_graph();
+		assert_eq!(moved.graph(), None);
+		assert_eq!(
+			moved.as_ref().into_triple().0,
+			quad.as_ref().into_triple().0
+		);
+
+		let h = Id::Iri(iri!("http://example.org/#h").to_owned());
+		let moved = quad.with_graph(Some(h.clone()));
+		assert_eq!(moved.graph(), Some(&h))
+	}
+
+	#[test]
+	fn parse_n_quads() {
+		let quad: LexicalQuad = "_:b0 <http://example.org/#p> <http://example.org/#o> ."
+			.parse()
+			.unwrap();
+		assert_eq!(quad.graph(), None);
+
+		let quad: LexicalQuad = "_:b0 <http://example.org/#p> \"1\"^^<http://www.w3.org/2001/XMLSchema#integer> <http://example.org/#g> ."
+			.parse()
+			.unwrap();
+		assert_eq!(
+			quad.graph(),
+			Some(&Id::Iri(iri!("http://example.org/#g").to_owned()))
+		);
+
+		let quad: LexicalQuad = "<http://example.org/#s> <http://example.org/#p> \"chat\"@fr _:g ."
+			.parse()
+			.unwrap();
+		assert_eq!(
+			quad.graph(),
+			Some(&Id::Blank(BlankIdBuf::from_suffix("g").unwrap()))
+		);
+	}
+
+	#[test]
+	fn parse_n_quads_round_trip() {
+		for input in [
+			"_:b0 <http://example.org/#p> _:b1 <http://example.org/#g> .",
+			"_:b0 <http://example.org/#p> \"a\\\"b\" _:g .",
+			"_:b0 <http://example.org/#p> \"a\"@en .",
+			"_:b0 <http://example.org/#p> \"a\"@ar-EG--rtl .",
+			"_:b0 <http://example.org/#p> \"1\"^^<http://www.w3.org/2001/XMLSchema#integer> _:g .",
+		] {
+			let quad: LexicalQuad = input.parse().unwrap();
+			assert_eq!(format!("{quad} ."), input)
+		}
+	}
+
+	#[test]
+	fn parse_n_quads_errors() {
+		assert!(matches!(
+			"_:b0 <http://example.org/#p> \"a\"^^_:t _:g .".parse::<LexicalQuad>(),
+			Err(InvalidQuad::Object(34))
+		));
+		assert!(matches!(
+			"_:b0 <http://example.org/#p> _:b1 \"g\" .".parse::<LexicalQuad>(),
+			Err(InvalidQuad::Graph(34))
+		));
+		assert!(matches!(
+			"_:b0 <http://example.org/#p> _:b1 _:g _:h .".parse::<LexicalQuad>(),
+			Err(InvalidQuad::End(38))
+		))
+	}
+
+	#[test]
+	fn group_by_graph_partitions() {
+		let quads: Vec<LexicalQuad> = [
+			"_:a <http://example.org/#p> _:b .",
+			"_:a <http://example.org/#p> _:c <http://example.org/#g> .",
+			"_:b <http://example.org/#p> _:c .",
+		]
+		.into_iter()
+		.map(|line| line.parse().unwrap())
+		.collect();
+
+		assert!(quads[0].is_default_graph());
+		assert!(!quads[1].is_default_graph());
+
+		let graphs = group_by_graph(quads.clone());
+		assert_eq!(graphs.len(), 2);
+		assert_eq!(
+			graphs[&None],
+			[
+				quads[0].clone().into_triple().0,
+				quads[2].clone().into_triple().0
+			]
+		);
+
+		let g = quads[1].graph().cloned();
+		assert_eq!(graphs[&g], [quads[1].clone().into_triple().0])
+	}
+
+	#[test]
+	fn rdf_display_graph() {
+		let quad: LexicalQuad = "_:a <http://example.org/#p> _:b .".parse().unwrap();
+		assert_eq!(quad.3.rdf_display().to_string(), "");
+		assert_eq!(
+			format!(
+				"{} {} {}{}",
+				quad.0.rdf_display(),
+				quad.1.rdf_display(),
+				quad.2.rdf_display(),
+				quad.3.rdf_display()
+			),
+			quad.to_string()
+		);
+
+		let quad = quad.with_graph(Some(Id::Blank(BlankIdBuf::from_suffix("g").unwrap())));
+		assert_eq!(
+			format!(
+				"{} {} {} {}",
+				quad.0.rdf_display(),
+				quad.1.rdf_display(),
+				quad.2.rdf_display(),
+				quad.3.rdf_display()
+			),
+			quad.to_string()
+		)
+	}
+
+	#[test]
+	fn try_export_quad() {
+		let quad: LexicalQuad =
+			"_:a <http://example.org/#p> \"1\"^^<http://example.org/#t> <http://example.org/#g> ."
+				.parse()
+				.unwrap();
+
+		let mut vocabulary: IndexVocabulary = IndexVocabulary::new();
+		let inserted = quad.inserted_into(&mut vocabulary);
+		assert_eq!(vocabulary.try_export_quad(inserted).unwrap(), quad);
+
+		let mut other: IndexVocabulary = IndexVocabulary::new();
+		other.insert_blank_id(BlankId::new("_:a").unwrap());
+		assert!(matches!(
+			other.try_export_quad(inserted),
+			Err(QuadExportFailed::Predicate(_))
+		))
+	}
+
+	#[test]
+	fn ground_eq() {
+		let parse = |s: &str| s.parse::<LexicalQuad>().unwrap();
+		let a = parse("_:a <http://example.org/#p> _:b _:g .");
+		let b = parse("_:x <http://example.org/#p> _:y _:h .");
+		assert!(a.ground_eq(&b));
+		assert!(a
+			.clone()
+			.into_triple()
+			.0
+			.ground_eq(&b.clone().into_triple().0));
+
+		let c = parse("_:x <http://example.org/#p> <http://example.org/#y> _:h .");
+		assert!(!a.ground_eq(&c));
+		assert!(!a.ground_eq(&b.into_default_graph()))
+	}
+}
@@ -39,3 +39,15 @@ pub const RDF_OBJECT: &Iri = iri!("http://www.w3.org/1999/02/22-rdf-syntax-ns#ob
 pub const RDF_VALUE: &Iri = iri!("http://www.w3.org/1999/02/22-rdf-syntax-ns#value");
 
 pub const RDF_NIL: &Iri = iri!("http://www.w3.org/1999/02/22-rdf-syntax-ns#nil");
+
+pub const XSD_BOOLEAN: &Iri = iri!("http://www.w3.org/2001/XMLSchema#boolean");
+pub const XSD_INTEGER: &Iri = iri!("http://www.w3.org/2001/XMLSchema#integer");
+pub const XSD_DECIMAL: &Iri = iri!("http://www.w3.org/2001/XMLSchema#decimal");
+pub const XSD_DOUBLE: &Iri = iri!("http://www.w3.org/2001/XMLSchema#double");
+pub const XSD_FLOAT: &Iri = iri!("http://www.w3.org/2001/XMLSchema#float");
+pub const XSD_DATE_TIME: &Iri = iri!("http://www.w3.org/2001/XMLSchema#dateTime");
+pub const XSD_DATE: &Iri = iri!("http://www.w3.org/2001/XMLSchema#date");
+pub const XSD_TIME: &Iri = iri!("http://www.w3.org/2001/XMLSchema#time");
+pub const XSD_ANY_URI: &Iri = iri!("http://www.w3.org/2001/XMLSchema#anyURI");
+pub const XSD_BASE64_BINARY: &Iri = iri!("http://www.w3.org/2001/XMLSchema#base64Binary");
+pub const XSD_HEX_BINARY: &Iri = iri!("http://www.w3.org/2001/XMLSchema#hexBinary");
@@ -0,0 +1,211 @@
+//! Serializes triples and quads as fixed-length arrays.
+//!
+//! A [`Triple`] is represented as a 3-element array, and a [`Quad`] as a
+//! 3-element array in the default graph or a 4-element array otherwise.
+//! Quads can be deserialized from both forms.
+//!
+//! # Example
+//!
+//! ```
+//! use rdf_types::LexicalQuad;
+//!
+//! #[derive(serde::Serialize, serde::Deserialize)]
+//! struct Statement {
+//!   #[serde(with = "rdf_types::serde::array")]
+//!   quad: LexicalQuad,
+//! }
+//! ```
+use std::{fmt, marker::PhantomData};
+
+use serde::{
+	de::{self, IgnoredAny, SeqAccess, Visitor},
+	ser::SerializeSeq,
+	Deserialize, Deserializer, Serialize, Serializer,
+};
+
+use crate::{Quad, Triple};
+
+/// Statement that can be serialized as an array.
+pub trait SerializeArray {
+	/// Serializes the statement as an array of its components.
+	fn serialize_array<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error>;
+}
+
+/// Statement that can be deserialized from an array.
+pub trait DeserializeArray<'de>: Sized {
+	/// Deserializes the statement from an array of its components.
+	fn deserialize_array<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error>;
+}
+
+impl<S: Serialize, P: Serialize, O: Serialize> SerializeArray for Triple<S, P, O> {
+	fn serialize_array<T: Serializer>(&self, serializer: T) -> Result<T::Ok, T::Error> {
+		let mut seq = serializer.serialize_seq(Some(3))?;
+		seq.serialize_element(&self.0)?;
+		seq.serialize_element(&self.1)?;
+		seq.serialize_element(&self.2)?;
+		seq.end()
+	}
+}
+
+impl<S: Serialize, P: Serialize, O: Serialize, G: Serialize> SerializeArray for Quad<S, P, O, G> {
+	fn serialize_array<T: Serializer>(&self, serializer: T) -> Result<T::Ok, T::Error> {
+		let len = if self.3.is_some() { 4 } else { 3 };
+		let mut seq = serializer.serialize_seq(Some(len))?;
+		seq.serialize_element(&self.0)?;
+		seq.serialize_element(&self.1)?;
+		seq.serialize_element(&self.2)?;
+		if let Some(g) = &self.3 {
+			seq.serialize_element(g)?;
+		}
+		seq.end()
+	}
+}
+
+/// Deserializes the next required element of an array.
+fn element<'de, A: SeqAccess<'de>, T: Deserialize<'de>>(
+	seq: &mut A,
+	index: usize,
+	expected: &dyn de::Expected,
+) -> Result<T, A::Error> {
+	seq.next_element()?
+		.ok_or_else(|| de::Error::invalid_length(index, expected))
+}
+
+/// Checks that there are no remaining elements in an array.
+fn end<'de, A: SeqAccess<'de>>(
+	seq: &mut A,
+	len: usize,
+	expected: &dyn de::Expected,
+) -> Result<(), A::Error> {
+	match seq.next_element::<IgnoredAny>()? {
+		Some(_) => Err(de::Error::invalid_length(len + 1, expected)),
+		None => Ok(()),
+	}
+}
+
+struct TripleVisitor<S, P, O>(PhantomData<(S, P, O)>);
+
+impl<'de, S: Deserialize<'de>, P: Deserialize<'de>, O: Deserialize<'de>> Visitor<'de>
+	for TripleVisitor<S, P, O>
+{
+	type Value = Triple<S, P, O>;
+
+	fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.write_str("an array of 3 elements")
+	}
+
+	fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+		let s = element(&mut seq, 0, &self)?;
+		let p = element(&mut seq, 1, &self)?;
+		let o = element(&mut seq, 2, &self)?;
+		end(&mut seq, 3, &self)?;
+		Ok(Triple(s, p, o))
+	}
+}
+
+impl<'de, S: Deserialize<'de>, P: Deserialize<'de>, O: Deserialize<'de>> DeserializeArray<'de>
+	for Triple<S, P, O>
+{
+	fn deserialize_array<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		deserializer.deserialize_seq(TripleVisitor(PhantomData))
+	}
+}
+
+struct QuadVisitor<S, P, O, G>(PhantomData<(S, P, O, G)>);
+
+impl<'de, S: Deserialize<'de>, P: Deserialize<'de>, O: Deserialize<'de>, G: Deserialize<'de>>
+	Visitor<'de> for QuadVisitor<S, P, O, G>
+{
+	type Value = Quad<S, P, O, G>;
+
+	fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.write_str("an array of 3 or 4 elements")
+	}
+
+	fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+		let s = element(&mut seq, 0, &self)?;
+		let p = element(&mut seq, 1, &self)?;
+		let o = element(&mut seq, 2, &self)?;
+		let g = seq.next_element()?;
+		end(&mut seq, 4, &self)?;
+		Ok(Quad(s, p, o, g))
+	}
+}
+
+impl<'de, S: Deserialize<'de>, P: Deserialize<'de>, O: Deserialize<'de>, G: Deserialize<'de>>
+	DeserializeArray<'de> for Quad<S, P, O, G>
+{
+	fn deserialize_array<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+		deserializer.deserialize_seq(QuadVisitor(PhantomData))
+	}
+}
+
+/// Serializes the given statement as an array.
+pub fn serialize<T: SerializeArray, S: Serializer>(
+	value: &T,
+	serializer: S,
+) -> Result<S::Ok, S::Error> {
+	value.serialize_array(serializer)
+}
+
+/// Deserializes a statement from an array.
+pub fn deserialize<'de, T: DeserializeArray<'de>, D: Deserializer<'de>>(
+	deserializer: D,
+) -> Result<T, D::Error> {
+	T::deserialize_array(deserializer)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use serde::de::value::{Error, SeqDeserializer};
+
+	fn seq(items: &[u32]) -> SeqDeserializer<std::iter::Copied<std::slice::Iter<'_, u32>>, Error> {
+		SeqDeserializer::new(items.iter().copied())
+	}
+
+	fn to_json<T: SerializeArray>(value: &T) -> serde_json::Value {
+		serialize(value, serde_json::value::Serializer).unwrap()
+	}
+
+	#[test]
+	fn serialize_quad() {
+		let quad: Quad<u32, u32, u32, u32> = Quad(1, 2, 3, None);
+		assert_eq!(to_json(&quad), serde_json::json!([1, 2, 3]));
+
+		let quad: Quad<u32, u32, u32, u32> = Quad(1, 2, 3, Some(4));
+		assert_eq!(to_json(&quad), serde_json::json!([1, 2, 3, 4]))
+	}
+
+	#[test]
+	fn serialize_triple() {
+		let triple: Triple<u32> = Triple(1, 2, 3);
+		assert_eq!(to_json(&triple), serde_json::json!([1, 2, 3]))
+	}
+
+	#[test]
+	fn deserialize_quad() {
+		let quad: Quad<u32> = deserialize(seq(&[1, 2, 3])).unwrap();
+		let expected: Quad<u32> = Quad(1, 2, 3, None);
+		assert_eq!(quad, expected);
+
+		let quad: Quad<u32> = deserialize(seq(&[1, 2, 3, 4])).unwrap();
+		let expected: Quad<u32> = Quad(1, 2, 3, Some(4));
+		assert_eq!(quad, expected);
+
+		for items in [&[1, 2][..], &[1, 2, 3, 4, 5]] {
+			let quad: Result<Quad<u32, u32, u32, u32>, _> = deserialize(seq(items));
+			assert!(quad.is_err())
+		}
+	}
+
+	#[test]
+	fn deserialize_triple() {
+		let triple: Triple<u32> = deserialize(seq(&[1, 2, 3])).unwrap();
+		let expected: Triple<u32> = Triple(1, 2, 3);
+		assert_eq!(triple, expected);
+
+		let triple: Result<Triple<u32, u32, u32>, _> = deserialize(seq(&[1, 2, 3, 4]));
+		assert!(triple.is_err())
+	}
+}
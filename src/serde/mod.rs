@@ -0,0 +1,7 @@
+//! Alternative serde representations.
+//!
+//! The `serde` implementations of terms derived by this crate follow the shape
+//! of the Rust types. The modules of this module provide other
+//! representations, to be used with the `#[serde(with = ...)]` attribute.
+pub mod array;
+pub mod ntriples;
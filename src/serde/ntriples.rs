@@ -0,0 +1,115 @@
+//! Serializes terms as their N-Triples lexical representation.
+//!
+//! Each term is represented by a single string such as
+//! `<http://example.org/>`, `_:b0` or `"value"^^<http://example.org/type>`,
+//! which is more compact and tool-friendly than the default representation.
+//!
+//! # Example
+//!
+//! ```
+//! use rdf_types::{Id, IriBuf, Term};
+//!
+//! #[derive(serde::Serialize, serde::Deserialize)]
+//! struct Statement {
+//!   #[serde(with = "rdf_types::serde::ntriples")]
+//!   subject: Id,
+//!
+//!   #[serde(with = "rdf_types::serde::ntriples")]
+//!   predicate: IriBuf,
+//!
+//!   #[serde(with = "rdf_types::serde::ntriples")]
+//!   object: Term,
+//! }
+//! ```
+use iref::IriBuf;
+use serde::{Deserialize, Deserializer, Serializer};
+
+use crate::{parse::Parser, Id, Literal, RdfDisplay, Term};
+
+/// Value with an N-Triples lexical representation.
+pub trait NTriplesTerm: RdfDisplay + Sized {
+	/// Parses the value from its N-Triples lexical representation.
+	///
+	/// Returns the byte offset at which the input is invalid in case of
+	/// error.
+	fn parse_ntriples(s: &str) -> Result<Self, usize>;
+}
+
+/// Parses the whole input with the given function.
+fn parse<T>(s: &str, f: impl FnOnce(&mut Parser) -> Result<T, usize>) -> Result<T, usize> {
+	let mut parser = Parser::new(s);
+	let value = f(&mut parser)?;
+	parser.finish()?;
+	Ok(value)
+}
+
+impl NTriplesTerm for IriBuf {
+	fn parse_ntriples(s: &str) -> Result<Self, usize> {
+		parse(s, |parser| parser.iri())
+	}
+}
+
+impl NTriplesTerm for Id {
+	fn parse_ntriples(s: &str) -> Result<Self, usize> {
+		parse(s, |parser| parser.id())
+	}
+}
+
+impl NTriplesTerm for Literal {
+	fn parse_ntriples(s: &str) -> Result<Self, usize> {
+		parse(s, |parser| parser.literal().map_err(|e| e.offset()))
+	}
+}
+
+impl NTriplesTerm for Term {
+	fn parse_ntriples(s: &str) -> Result<Self, usize> {
+		parse(s, |parser| parser.term())
+	}
+}
+
+/// Serializes the given value as its N-Triples lexical representation.
+pub fn serialize<T: NTriplesTerm, S: Serializer>(
+	value: &T,
+	serializer: S,
+) -> Result<S::Ok, S::Error> {
+	serializer.collect_str(&value.rdf_display())
+}
+
+/// Deserializes a value from its N-Triples lexical representation.
+pub fn deserialize<'de, T: NTriplesTerm, D: Deserializer<'de>>(
+	deserializer: D,
+) -> Result<T, D::Error> {
+	let s = String::deserialize(deserializer)?;
+	T::parse_ntriples(&s).map_err(|offset| {
+		serde::de::Error::custom(format!(
+			"invalid N-Triples term `{s}` at byte offset {offset}"
+		))
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use serde::de::value::{Error, StrDeserializer};
+
+	#[test]
+	fn round_trip() {
+		for input in [
+			"<http://example.org/#a>",
+			"_:b0",
+			"\"v\"@en",
+			"\"a\\nb\"",
+			"\"1\"^^<http://www.w3.org/2001/XMLSchema#integer>",
+		] {
+			let term: Term = deserialize(StrDeserializer::<Error>::new(input)).unwrap();
+			assert_eq!(term.rdf_display().to_string(), input)
+		}
+
+		let id: Result<Id, _> = deserialize(StrDeserializer::<Error>::new("\"v\""));
+		assert!(id.is_err());
+
+		let iri: Result<IriBuf, _> =
+			deserialize(StrDeserializer::<Error>::new("<http://example.org/> ."));
+		assert!(iri.is_err())
+	}
+}
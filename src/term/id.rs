@@ -5,13 +5,14 @@ use std::{cmp::Ordering, fmt, hash::Hash};
 use locspan_derive::*;
 
 use crate::{
+	display::IriEscaper,
 	vocabulary::{
 		BlankIdVocabulary, ByRef, EmbedIntoVocabulary, EmbeddedIntoVocabulary,
 		ExtractFromVocabulary, ExtractedFromVocabulary, IriVocabulary,
 	},
-	BlankId, BlankIdBuf, LexicalGraphLabelRef, LexicalSubjectRef, MaybeBlankId, MaybeIri,
-	RdfDisplay, Term, TryAsBlankId, TryAsIri, TryIntoBlankId, TryIntoIri, Vocabulary,
-	VocabularyMut,
+	BlankId, BlankIdBuf, GraphLabel, LexicalGraphLabelRef, LexicalSubjectRef, MaybeBlankId,
+	MaybeIri, RdfDisplay, Subject, Term, TryAsBlankId, TryAsIri, TryIntoBlankId, TryIntoIri,
+	Vocabulary, VocabularyMut,
 };
 
 /// RDF node identifier.
@@ -96,6 +97,32 @@ impl<I, B> Id<I, B> {
 		Term::Id(self)
 	}
 
+	/// Uses this graph label as a subject.
+	///
+	/// This is the identity function: it only documents the change of RDF role
+	/// at the call site.
+	pub fn into_subject(self) -> Subject<I, B> {
+		self
+	}
+
+	/// Uses this subject as a graph label.
+	///
+	/// This is the identity function: it only documents the change of RDF role
+	/// at the call site.
+	pub fn into_graph_label(self) -> GraphLabel<I, B> {
+		self
+	}
+
+	/// Borrows this graph label as a subject.
+	pub fn as_subject(&self) -> &Subject<I, B> {
+		self
+	}
+
+	/// Borrows this subject as a graph label.
+	pub fn as_graph_label(&self) -> &GraphLabel<I, B> {
+		self
+	}
+
 	/// Returns a borrowed string representation of the id.
 	pub fn as_str(&self) -> &str
 	where
@@ -108,6 +135,39 @@ impl<I, B> Id<I, B> {
 		}
 	}
 
+	/// Compares the two identifiers, considering that every blank node
+	/// identifier is equal to any other blank node identifier.
+	pub fn ground_eq(&self, other: &Self) -> bool
+	where
+		I: PartialEq,
+	{
+		match (self, other) {
+			(Self::Iri(a), Self::Iri(b)) => a == b,
+			(Self::Blank(_), Self::Blank(_)) => true,
+			_ => false,
+		}
+	}
+
+	/// Compares the two identifiers according to a total order, placing
+	/// blank node identifiers before IRIs, and comparing identifiers of the
+	/// same kind by their string representation.
+	///
+	/// Unlike the [`Ord`] implementation, this order only depends on the
+	/// lexical representation of the identifiers, and is guaranteed to stay
+	/// the same in future versions of this crate.
+	pub fn cmp_total(&self, other: &Self) -> Ordering
+	where
+		I: AsRef<str>,
+		B: AsRef<str>,
+	{
+		match (self, other) {
+			(Self::Blank(a), Self::Blank(b)) => a.as_ref().cmp(b.as_ref()),
+			(Self::Blank(_), Self::Iri(_)) => Ordering::Less,
+			(Self::Iri(_), Self::Blank(_)) => Ordering::Greater,
+			(Self::Iri(a), Self::Iri(b)) => a.as_ref().cmp(b.as_ref()),
+		}
+	}
+
 	/// Converts an `&Id<I, B>` into an `Id<&I, &B>`.
 	pub fn as_ref(&self) -> Id<&I, &B> {
 		match self {
@@ -128,6 +188,27 @@ impl<V, I: EmbedIntoVocabulary<V>, B: EmbedIntoVocabulary<V>> EmbedIntoVocabular
 	}
 }
 
+/// Lifts a node identifier into the term `Term::Id(id)`.
+///
+/// This is equivalent to [`Id::into_term`].
+impl<I, B, L> From<Id<I, B>> for Term<Id<I, B>, L> {
+	fn from(value: Id<I, B>) -> Self {
+		value.into_term()
+	}
+}
+
+/// Converts a term into a node identifier (subject or graph label).
+///
+/// Fails with the literal value if the term is a literal, since literals
+/// cannot be used as subjects or graph labels.
+impl<I, B, L> TryFrom<Term<Id<I, B>, L>> for Id<I, B> {
+	type Error = L;
+
+	fn try_from(value: Term<Id<I, B>, L>) -> Result<Self, L> {
+		value.try_into_id()
+	}
+}
+
 impl<V, I: EmbeddedIntoVocabulary<V>, B: EmbeddedIntoVocabulary<V>> EmbeddedIntoVocabulary<V>
 	for Id<I, B>
 {
@@ -299,6 +380,40 @@ impl<I1: PartialEq<I2>, B1: PartialEq<B2>, I2, B2> PartialEq<Id<I2, B2>> for Id<
 	}
 }
 
+/// Checks that the identifier is the given IRI.
+impl<B> PartialEq<Iri> for Id<IriBuf, B> {
+	fn eq(&self, other: &Iri) -> bool {
+		match self {
+			Self::Iri(iri) => iri.as_iri() == other,
+			Self::Blank(_) => false,
+		}
+	}
+}
+
+/// Checks that the identifier is the given blank node identifier.
+impl<I> PartialEq<BlankId> for Id<I, BlankIdBuf> {
+	fn eq(&self, other: &BlankId) -> bool {
+		match self {
+			Self::Iri(_) => false,
+			Self::Blank(id) => id.as_blank_id_ref() == other,
+		}
+	}
+}
+
+/// Compares the string representation of the identifier, as returned by
+/// [`Id::as_str`].
+impl<I: AsRef<str>, B: AsRef<str>> PartialEq<str> for Id<I, B> {
+	fn eq(&self, other: &str) -> bool {
+		self.as_str() == other
+	}
+}
+
+impl<'a, I: AsRef<str>, B: AsRef<str>> PartialEq<&'a str> for Id<I, B> {
+	fn eq(&self, other: &&'a str) -> bool {
+		self.as_str() == *other
+	}
+}
+
 impl<I1: PartialOrd<I2>, B1: PartialOrd<B2>, I2, B2> PartialOrd<Id<I2, B2>> for Id<I1, B1> {
 	fn partial_cmp(&self, other: &Id<I2, B2>) -> Option<Ordering> {
 		match (self, other) {
@@ -336,7 +451,12 @@ impl<I: fmt::Display, B: fmt::Display> RdfDisplay for Id<I, B> {
 	fn rdf_fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
 		match self {
 			Self::Blank(id) => id.fmt(f),
-			Self::Iri(iri) => write!(f, "<{iri}>"),
+			Self::Iri(iri) => {
+				use fmt::Write;
+				write!(f, "<")?;
+				write!(IriEscaper(f), "{iri}")?;
+				write!(f, ">")
+			}
 		}
 	}
 }
@@ -349,7 +469,7 @@ impl<V: crate::vocabulary::IriVocabulary + crate::vocabulary::BlankIdVocabulary>
 		use fmt::Display;
 		match self {
 			Self::Blank(id) => vocabulary.blank_id(id).unwrap().fmt(f),
-			Self::Iri(iri) => write!(f, "<{}>", vocabulary.iri(iri).unwrap()),
+			Self::Iri(iri) => vocabulary.iri(iri).unwrap().rdf_fmt(f),
 		}
 	}
 }
@@ -1,4 +1,6 @@
-use crate::Term;
+use iref::{Iri, IriBuf};
+
+use crate::{BlankId, BlankIdBuf, Id, LexicalTermRef, Literal, Term};
 
 /// Type that can be turned into a [`Term`].
 pub trait IntoTerm {
@@ -21,3 +23,59 @@ impl<I, L> IntoTerm for Term<I, L> {
 		self
 	}
 }
+
+impl IntoTerm for IriBuf {
+	type Id = Id;
+
+	type Literal = Literal;
+
+	fn into_term(self) -> Term {
+		Term::Id(Id::Iri(self))
+	}
+}
+
+impl IntoTerm for BlankIdBuf {
+	type Id = Id;
+
+	type Literal = Literal;
+
+	fn into_term(self) -> Term {
+		Term::Id(Id::Blank(self))
+	}
+}
+
+/// Type that can be borrowed as a lexical [`Term`].
+pub trait AsTerm {
+	/// Borrows the value as a lexical term.
+	fn as_term(&self) -> LexicalTermRef;
+}
+
+impl AsTerm for Term {
+	fn as_term(&self) -> LexicalTermRef {
+		self.as_lexical_term_ref()
+	}
+}
+
+impl AsTerm for Iri {
+	fn as_term(&self) -> LexicalTermRef {
+		Term::Id(Id::Iri(self))
+	}
+}
+
+impl AsTerm for IriBuf {
+	fn as_term(&self) -> LexicalTermRef {
+		self.as_iri().as_term()
+	}
+}
+
+impl AsTerm for BlankId {
+	fn as_term(&self) -> LexicalTermRef {
+		Term::Id(Id::Blank(self))
+	}
+}
+
+impl AsTerm for BlankIdBuf {
+	fn as_term(&self) -> LexicalTermRef {
+		self.as_blank_id_ref().as_term()
+	}
+}
@@ -3,7 +3,7 @@ use crate::vocabulary::{
 	ByRef, EmbedIntoVocabulary, EmbeddedIntoVocabulary, ExtractFromVocabulary,
 	ExtractedFromVocabulary, LiteralVocabulary, TryExtractFromVocabulary,
 };
-use crate::{BlankIdBuf, Literal, RdfDisplay};
+use crate::{BlankIdBuf, Literal, LiteralType, RdfDisplay};
 use iref::IriBuf;
 use std::fmt;
 use std::{cmp::Ordering, hash::Hash};
@@ -13,11 +13,17 @@ mod into;
 mod maybe_blank;
 mod maybe_iri;
 mod maybe_literal;
+#[cfg(feature = "star")]
+mod star;
+mod visitor;
 
 pub use id::*;
 pub use into::*;
 pub use maybe_blank::*;
 pub use maybe_iri::*;
+#[cfg(feature = "star")]
+pub use star::*;
+pub use visitor::*;
 // pub use maybe_literal::*;
 
 #[cfg(feature = "contextual")]
@@ -33,8 +39,18 @@ use locspan_derive::*;
 /// # `Hash` implementation
 ///
 /// It is guaranteed that the `Hash` implementation of `Term` is *transparent*,
-/// meaning that the hash of `Term::Id(id)` the same as `id` and the hash of
+/// meaning that the hash of `Term::Id(id)` is the same as `id` and the hash of
 /// `Term::Literal(l)` is the same as `l`.
+///
+/// Since the `Hash` implementation of [`Id`] is also transparent, an IRI
+/// `iri` has the same hash as `Id::Iri(iri)` and `Term::Id(Id::Iri(iri))`,
+/// and the same goes for blank node identifiers. [`Subject`] and
+/// [`GraphLabel`] are aliases of `Id`, and hash the same way.
+///
+/// Note that none of those types can borrow each other, so standard
+/// collections such as `HashSet<Term>` cannot be directly queried with an
+/// `Id`. The guarantee is only useful to collections supporting
+/// heterogeneous lookups with a compatible hash and equality.
 #[derive(Clone, Copy, Eq, Ord, Debug)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(
@@ -217,6 +233,24 @@ impl<I, L> Term<I, L> {
 			Self::Literal(l) => Term::Literal(l),
 		}
 	}
+
+	/// Maps the node identifier with the given function.
+	pub fn map_id<J>(self, f: impl FnOnce(I) -> J) -> Term<J, L> {
+		self.map(f, |l| l)
+	}
+
+	/// Maps the literal with the given function.
+	pub fn map_literal<M>(self, f: impl FnOnce(L) -> M) -> Term<I, M> {
+		self.map(|id| id, f)
+	}
+
+	/// Maps the node identifier or literal with the given functions.
+	pub fn map<J, M>(self, id: impl FnOnce(I) -> J, literal: impl FnOnce(L) -> M) -> Term<J, M> {
+		match self {
+			Self::Id(i) => Term::Id(id(i)),
+			Self::Literal(l) => Term::Literal(literal(l)),
+		}
+	}
 }
 
 impl<I: LiteralInterpretationMut<L>, T: Interpret<I, Interpreted = I::Resource>, L> Interpret<I>
@@ -347,6 +381,93 @@ impl Term {
 	pub fn as_lexical_object_ref(&self) -> LexicalObjectRef {
 		self.as_lexical_term_ref()
 	}
+
+	/// Borrows this term as a subject, if it is not a literal.
+	pub fn try_as_subject_ref(&self) -> Option<LexicalSubjectRef> {
+		self.as_id().map(Id::as_lexical_subject_ref)
+	}
+
+	/// Turns this object into a subject, or returns the literal if it is not
+	/// a node identifier.
+	pub fn into_subject(self) -> Result<Subject, Literal> {
+		self.try_into_id()
+	}
+}
+
+impl<I, B, L> Term<Id<I, B>, L> {
+	/// Compares the two terms, considering that every blank node identifier is
+	/// equal to any other blank node identifier.
+	pub fn ground_eq(&self, other: &Self) -> bool
+	where
+		I: PartialEq,
+		L: PartialEq,
+	{
+		match (self, other) {
+			(Self::Id(a), Self::Id(b)) => a.ground_eq(b),
+			(Self::Literal(a), Self::Literal(b)) => a == b,
+			_ => false,
+		}
+	}
+}
+
+impl<I: AsRef<str>, B: AsRef<str>, S: AsRef<str>> Term<Id<I, B>, Literal<I, S>> {
+	/// Compares the two terms according to a total order, placing blank node
+	/// identifiers first, then IRIs, then literals.
+	///
+	/// Node identifiers are compared with [`Id::cmp_total`]. Literals are
+	/// compared by value, then by type: typed literals (ordered by datatype
+	/// IRI) come before language strings, which come before directional
+	/// language strings (ordered by language tag, then direction).
+	///
+	/// Unlike the [`Ord`] implementation, this order only depends on the
+	/// lexical representation of the terms, and is guaranteed to stay the same
+	/// in future versions of this crate.
+	pub fn cmp_total(&self, other: &Self) -> Ordering {
+		match (self, other) {
+			(Self::Id(a), Self::Id(b)) => a.cmp_total(b),
+			(Self::Id(_), Self::Literal(_)) => Ordering::Less,
+			(Self::Literal(_), Self::Id(_)) => Ordering::Greater,
+			(Self::Literal(a), Self::Literal(b)) => a
+				.value
+				.as_ref()
+				.cmp(b.value.as_ref())
+				.then_with(|| cmp_literal_type_total(&a.type_, &b.type_)),
+		}
+	}
+}
+
+/// Total order on literal types used by [`Term::cmp_total`].
+fn cmp_literal_type_total<I: AsRef<str>>(a: &LiteralType<I>, b: &LiteralType<I>) -> Ordering {
+	fn rank<I>(ty: &LiteralType<I>) -> u8 {
+		match ty {
+			LiteralType::Any(_) => 0,
+			LiteralType::LangString(_) => 1,
+			LiteralType::DirLangString(_, _) => 2,
+		}
+	}
+
+	match (a, b) {
+		(LiteralType::Any(a), LiteralType::Any(b)) => a.as_ref().cmp(b.as_ref()),
+		(LiteralType::LangString(a), LiteralType::LangString(b)) => a.as_str().cmp(b.as_str()),
+		(LiteralType::DirLangString(a, da), LiteralType::DirLangString(b, db)) => {
+			a.as_str().cmp(b.as_str()).then(da.cmp(db))
+		}
+		_ => rank(a).cmp(&rank(b)),
+	}
+}
+
+impl<I, T> Term<I, Literal<T>> {
+	/// Returns the datatype IRI of this term, if it is a literal that is not
+	/// a language string.
+	pub fn literal_datatype(&self) -> Option<&T> {
+		match self {
+			Self::Literal(l) => match &l.type_ {
+				LiteralType::Any(ty) => Some(ty),
+				_ => None,
+			},
+			Self::Id(_) => None,
+		}
+	}
 }
 
 impl<V, I: EmbedIntoVocabulary<V>, L: EmbedIntoVocabulary<V>> EmbedIntoVocabulary<V>
@@ -453,6 +574,14 @@ impl<'a> LexicalTermRef<'a> {
 			Self::Literal(l) => Term::Literal(l.clone()),
 		}
 	}
+
+	/// Returns this term as a subject, if it is not a literal.
+	pub fn try_as_subject(&self) -> Option<LexicalSubjectRef<'a>> {
+		match self {
+			Self::Id(id) => Some(*id),
+			Self::Literal(_) => None,
+		}
+	}
 }
 
 /// RDF triple/quad subject.
@@ -493,3 +622,220 @@ impl<I, B, L> AsRdfTerm<I, B, L> for Term<Id<I, B>, L> {
 		}
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{BlankId, XSD_INTEGER, XSD_STRING};
+	use langtag::LangTagBuf;
+	use static_iref::iri;
+
+	#[test]
+	fn map() {
+		let iri: Object = Term::iri(iri!("http://example.org/#a").to_owned());
+		let literal: Object = Term::Literal(Literal::new(
+			"a".to_owned(),
+			LiteralType::Any(XSD_STRING.to_owned()),
+		));
+
+		let expected: Term<Id, String> = Term::Literal("a".to_owned());
+		assert_eq!(literal.clone().map_literal(|l| l.value), expected);
+
+		let expected: Term<bool> = Term::Literal(literal.as_literal().unwrap().clone());
+		assert_eq!(literal.clone().map_id(|id| id.is_iri()), expected);
+
+		let expected: Term<bool, String> = Term::Id(true);
+		assert_eq!(iri.map(|id| id.is_iri(), |l| l.value), expected)
+	}
+
+	#[test]
+	fn literal_datatype() {
+		let integer: Object = Term::Literal(Literal::new(
+			"1".to_owned(),
+			LiteralType::Any(XSD_INTEGER.to_owned()),
+		));
+		let lang_string: Object = Term::Literal(Literal::new(
+			"a".to_owned(),
+			LiteralType::LangString(LangTagBuf::new("en".to_owned()).unwrap()),
+		));
+		let iri: Object = Term::iri(iri!("http://example.org/#a").to_owned());
+
+		assert_eq!(integer.literal_datatype(), Some(&XSD_INTEGER.to_owned()));
+		assert_eq!(lang_string.literal_datatype(), None);
+		assert_eq!(iri.literal_datatype(), None)
+	}
+
+	#[test]
+	fn try_as_subject() {
+		let iri: Object = Term::iri(iri!("http://example.org/#a").to_owned());
+		let blank: Object = Term::blank(BlankIdBuf::from_suffix("b0").unwrap());
+		let literal: Object = Term::Literal(Literal::new(
+			"a".to_owned(),
+			LiteralType::Any(XSD_STRING.to_owned()),
+		));
+
+		assert_eq!(
+			iri.try_as_subject_ref(),
+			Some(Id::Iri(iri!("http://example.org/#a")))
+		);
+		assert_eq!(
+			blank.as_lexical_object_ref().try_as_subject(),
+			Some(Id::Blank(BlankId::new("_:b0").unwrap()))
+		);
+		assert_eq!(literal.try_as_subject_ref(), None);
+		assert_eq!(literal.as_lexical_object_ref().try_as_subject(), None)
+	}
+
+	#[test]
+	fn display_escaped_iri() {
+		let id: Id<&str, &str> = Id::Iri("http://example.org/a b\x01");
+		assert_eq!(
+			id.rdf_display().to_string(),
+			"<http://example.org/a\\u0020b\\u0001>"
+		);
+
+		let term: Term<Id<&str, &str>> = Term::Id(id);
+		assert_eq!(
+			term.rdf_display().to_string(),
+			"<http://example.org/a\\u0020b\\u0001>"
+		)
+	}
+
+	#[test]
+	fn try_from_term() {
+		let iri = iri!("http://example.org/#a").to_owned();
+		let object: Object = Term::iri(iri.clone());
+		assert_eq!(Subject::try_from(object), Ok(Subject::Iri(iri)));
+
+		let literal = Literal::new("a".to_owned(), LiteralType::Any(XSD_STRING.to_owned()));
+		let object: Object = Term::Literal(literal.clone());
+		assert_eq!(GraphLabel::try_from(object), Err(literal))
+	}
+
+	#[test]
+	fn into_subject() {
+		let subject = Subject::Blank(BlankId::new("_:b0").unwrap().to_owned());
+		let object: Object = subject.clone().into_term();
+		assert_eq!(object.into_subject(), Ok(subject));
+
+		let literal = Literal::new("a".to_owned(), LiteralType::Any(XSD_STRING.to_owned()));
+		assert_eq!(
+			Object::Literal(literal.clone()).into_subject(),
+			Err(literal)
+		)
+	}
+
+	#[test]
+	fn compare_subject() {
+		let iri = iri!("http://example.org/#a");
+		let subject: Subject = Subject::Iri(iri.to_owned());
+		assert_eq!(subject, *iri);
+		assert_eq!(subject, "http://example.org/#a");
+		assert_ne!(subject, *iri!("http://example.org/#b"));
+
+		let blank = BlankId::new("_:b0").unwrap();
+		let subject: Subject = Subject::Blank(blank.to_owned());
+		assert_eq!(subject, *blank);
+		assert_ne!(subject, *iri);
+		assert_eq!(subject, *"_:b0")
+	}
+
+	#[test]
+	fn from_subject() {
+		fn object(value: impl Into<Object>) -> Object {
+			value.into()
+		}
+
+		let subject: Subject = Subject::Blank(BlankId::new("_:b0").unwrap().to_owned());
+		let term: Object = subject.clone().into_term();
+		assert_eq!(object(subject), term)
+	}
+
+	#[test]
+	fn bare_into_term() {
+		let iri = iri!("http://example.org/#a").to_owned();
+		let blank = BlankId::new("_:b0").unwrap().to_owned();
+
+		let term = iri.clone().into_term();
+		let expected: Term = Term::Id(Id::Iri(iri.clone()));
+		assert_eq!(term, expected);
+		assert_eq!(iri.as_term(), term.as_term());
+
+		let term = blank.clone().into_term();
+		let expected: Term = Term::Id(Id::Blank(blank.clone()));
+		assert_eq!(term, expected);
+		assert_eq!(blank.as_term(), term.as_lexical_term_ref())
+	}
+
+	#[test]
+	fn graph_label_role() {
+		let graph: GraphLabel = "_:g".parse::<BlankIdBuf>().map(Id::Blank).unwrap();
+		let subject: Subject = graph.clone().into_subject();
+		assert_eq!(subject.as_graph_label(), &graph);
+		assert_eq!(subject.into_graph_label(), graph)
+	}
+
+	#[test]
+	fn transparent_hash() {
+		use std::collections::hash_map::DefaultHasher;
+		use std::hash::Hasher;
+
+		fn hash(value: impl Hash) -> u64 {
+			let mut hasher = DefaultHasher::new();
+			value.hash(&mut hasher);
+			hasher.finish()
+		}
+
+		let iri = iri!("http://example.org/#a").to_owned();
+		let subject: Subject = Id::Iri(iri.clone());
+		let object: Object = Term::Id(subject.clone());
+		assert_eq!(hash(&iri), hash(&subject));
+		assert_eq!(hash(&subject), hash(&object));
+
+		let blank = BlankId::new("_:b0").unwrap().to_owned();
+		let graph: GraphLabel = Id::Blank(blank.clone());
+		let object: Object = Term::Id(graph.clone());
+		assert_eq!(hash(&blank), hash(&graph));
+		assert_eq!(hash(&graph), hash(&object));
+
+		let literal = Literal::new_string("a");
+		let object: Object = Term::Literal(literal.clone());
+		assert_eq!(hash(&literal), hash(&object))
+	}
+
+	#[test]
+	fn cmp_total() {
+		let lang = |tag: &str| LiteralType::LangString(LangTagBuf::new(tag.to_owned()).unwrap());
+		let mut terms: Vec<Term> = vec![
+			Term::Literal(Literal::new("a".to_owned(), lang("en"))),
+			Term::iri(iri!("http://example.org/#b").to_owned()),
+			Term::Literal(Literal::new_typed("a", XSD_STRING.to_owned())),
+			Term::blank(BlankIdBuf::new("_:z").unwrap()),
+			Term::Literal(Literal::new_typed("1", XSD_INTEGER.to_owned())),
+			Term::iri(iri!("http://example.org/#a").to_owned()),
+		];
+		terms.sort_by(Term::cmp_total);
+
+		let expected: Vec<String> = [
+			"_:z",
+			"<http://example.org/#a>",
+			"<http://example.org/#b>",
+			"\"1\"^^<http://www.w3.org/2001/XMLSchema#integer>",
+			"\"a\"",
+			"\"a\"@en",
+		]
+		.into_iter()
+		.map(str::to_owned)
+		.collect();
+		assert_eq!(
+			terms
+				.iter()
+				.map(|t| t.rdf_display().to_string())
+				.collect::<Vec<_>>(),
+			expected
+		);
+
+		let subject: Subject = Id::Iri(iri!("http://example.org/#a").to_owned());
+		assert_eq!(subject.cmp_total(&subject.clone()), Ordering::Equal)
+	}
+}
@@ -0,0 +1,140 @@
+use std::fmt;
+
+use crate::{Id, Literal, RdfDisplay, Term, Triple};
+
+#[cfg(feature = "contextual")]
+use contextual::{DisplayWithContext, WithContext};
+
+#[cfg(feature = "contextual")]
+use crate::RdfDisplayWithContext;
+
+/// Quoted triple, whose components may themselves be quoted triples.
+pub type QuotedTriple<I = Id, L = Literal> = Triple<StarTerm<I, L>>;
+
+/// RDF-star term.
+///
+/// Either a regular [`Term`] or a quoted triple, that can be used as the
+/// subject or object of another triple.
+#[derive(Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum StarTerm<I = Id, L = Literal> {
+	/// Regular term.
+	Term(Term<I, L>),
+
+	/// Quoted triple.
+	Triple(Box<QuotedTriple<I, L>>),
+}
+
+impl<I, L> StarTerm<I, L> {
+	pub fn is_term(&self) -> bool {
+		matches!(self, Self::Term(_))
+	}
+
+	pub fn is_triple(&self) -> bool {
+		matches!(self, Self::Triple(_))
+	}
+
+	pub fn as_term(&self) -> Option<&Term<I, L>> {
+		match self {
+			Self::Term(t) => Some(t),
+			_ => None,
+		}
+	}
+
+	pub fn as_triple(&self) -> Option<&QuotedTriple<I, L>> {
+		match self {
+			Self::Triple(t) => Some(t),
+			_ => None,
+		}
+	}
+
+	pub fn into_term(self) -> Option<Term<I, L>> {
+		match self {
+			Self::Term(t) => Some(t),
+			_ => None,
+		}
+	}
+
+	pub fn into_triple(self) -> Option<QuotedTriple<I, L>> {
+		match self {
+			Self::Triple(t) => Some(*t),
+			_ => None,
+		}
+	}
+}
+
+impl<I, L> From<Term<I, L>> for StarTerm<I, L> {
+	fn from(value: Term<I, L>) -> Self {
+		Self::Term(value)
+	}
+}
+
+impl<I, L> From<QuotedTriple<I, L>> for StarTerm<I, L> {
+	fn from(value: QuotedTriple<I, L>) -> Self {
+		Self::Triple(Box::new(value))
+	}
+}
+
+impl<I: RdfDisplay, L: RdfDisplay> fmt::Display for StarTerm<I, L> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		self.rdf_fmt(f)
+	}
+}
+
+impl<I: RdfDisplay, L: RdfDisplay> RdfDisplay for StarTerm<I, L> {
+	fn rdf_fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			Self::Term(t) => t.rdf_fmt(f),
+			Self::Triple(t) => write!(f, "<< {t} >>"),
+		}
+	}
+}
+
+#[cfg(feature = "contextual")]
+impl<I: RdfDisplayWithContext<V>, L: RdfDisplayWithContext<V>, V> DisplayWithContext<V>
+	for StarTerm<I, L>
+{
+	fn fmt_with(&self, vocabulary: &V, f: &mut fmt::Formatter) -> fmt::Result {
+		self.rdf_fmt_with(vocabulary, f)
+	}
+}
+
+#[cfg(feature = "contextual")]
+impl<I: RdfDisplayWithContext<V>, L: RdfDisplayWithContext<V>, V> RdfDisplayWithContext<V>
+	for StarTerm<I, L>
+{
+	fn rdf_fmt_with(&self, vocabulary: &V, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			Self::Term(t) => t.rdf_fmt_with(vocabulary, f),
+			Self::Triple(t) => write!(f, "<< {} >>", (**t).with(vocabulary)),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::BlankIdBuf;
+	use static_iref::iri;
+
+	#[test]
+	fn display_quoted_triple() {
+		let quoted: QuotedTriple = Triple(
+			Term::blank(BlankIdBuf::from_suffix("b0").unwrap()).into(),
+			Term::iri(iri!("http://example.org/#p").to_owned()).into(),
+			Term::iri(iri!("http://example.org/#o").to_owned()).into(),
+		);
+
+		let triple: QuotedTriple = Triple(
+			quoted.into(),
+			Term::iri(iri!("http://example.org/#certainty").to_owned()).into(),
+			Term::blank(BlankIdBuf::from_suffix("b1").unwrap()).into(),
+		);
+
+		assert!(triple.0.is_triple());
+		assert_eq!(
+			triple.to_string(),
+			"<< _:b0 <http://example.org/#p> <http://example.org/#o> >> <http://example.org/#certainty> _:b1"
+		)
+	}
+}
@@ -0,0 +1,112 @@
+use crate::{Id, Term};
+
+/// Term visitor.
+///
+/// Provides one method per kind of term, so that a single visitor can process
+/// every term regardless of its position in a triple or quad. Terms are
+/// dispatched to the visitor using their `accept` method.
+pub trait TermVisitor<I, B, L> {
+	/// Output of each visit.
+	type Output;
+
+	/// Visits an IRI.
+	fn visit_iri(&mut self, iri: &I) -> Self::Output;
+
+	/// Visits a blank node identifier.
+	fn visit_blank(&mut self, blank_id: &B) -> Self::Output;
+
+	/// Visits a literal value.
+	fn visit_literal(&mut self, literal: &L) -> Self::Output;
+}
+
+impl<I, B, L, V: TermVisitor<I, B, L>> TermVisitor<I, B, L> for &mut V {
+	type Output = V::Output;
+
+	fn visit_iri(&mut self, iri: &I) -> Self::Output {
+		V::visit_iri(*self, iri)
+	}
+
+	fn visit_blank(&mut self, blank_id: &B) -> Self::Output {
+		V::visit_blank(*self, blank_id)
+	}
+
+	fn visit_literal(&mut self, literal: &L) -> Self::Output {
+		V::visit_literal(*self, literal)
+	}
+}
+
+impl<I, B> Id<I, B> {
+	/// Dispatches this node identifier to the given visitor.
+	pub fn accept<L, V: TermVisitor<I, B, L>>(&self, mut visitor: V) -> V::Output {
+		match self {
+			Self::Iri(iri) => visitor.visit_iri(iri),
+			Self::Blank(blank_id) => visitor.visit_blank(blank_id),
+		}
+	}
+}
+
+impl<I, B, L> Term<Id<I, B>, L> {
+	/// Dispatches this term to the given visitor.
+	pub fn accept<V: TermVisitor<I, B, L>>(&self, mut visitor: V) -> V::Output {
+		match self {
+			Self::Id(id) => id.accept(visitor),
+			Self::Literal(literal) => visitor.visit_literal(literal),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{BlankIdBuf, Literal, LiteralType, Quad};
+	use iref::IriBuf;
+	use static_iref::iri;
+
+	#[derive(Default)]
+	struct Counter {
+		iris: usize,
+		blank_ids: usize,
+		literals: usize,
+	}
+
+	impl TermVisitor<IriBuf, BlankIdBuf, Literal> for Counter {
+		type Output = ();
+
+		fn visit_iri(&mut self, _iri: &IriBuf) {
+			self.iris += 1
+		}
+
+		fn visit_blank(&mut self, _blank_id: &BlankIdBuf) {
+			self.blank_ids += 1
+		}
+
+		fn visit_literal(&mut self, _literal: &Literal) {
+			self.literals += 1
+		}
+	}
+
+	#[test]
+	fn count_quad_terms() {
+		let quad: Quad = Quad(
+			Term::blank(BlankIdBuf::from_suffix("0").unwrap()),
+			Term::iri(iri!("http://example.org/#p").to_owned()),
+			Term::Literal(Literal::new(
+				"value".to_owned(),
+				LiteralType::Any(iri!("http://www.w3.org/2001/XMLSchema#string").to_owned()),
+			)),
+			Some(Term::iri(iri!("http://example.org/#g").to_owned())),
+		);
+
+		let mut counter = Counter::default();
+		quad.0.accept(&mut counter);
+		quad.1.accept(&mut counter);
+		quad.2.accept(&mut counter);
+		if let Some(g) = &quad.3 {
+			g.accept(&mut counter)
+		}
+
+		assert_eq!(counter.iris, 2);
+		assert_eq!(counter.blank_ids, 1);
+		assert_eq!(counter.literals, 1)
+	}
+}
@@ -1,11 +1,12 @@
-use std::{cmp::Ordering, fmt};
+use std::{cmp::Ordering, fmt, str::FromStr};
 
 use iref::{Iri, IriBuf};
 
 use crate::{
+	parse::Parser,
 	vocabulary::{
 		ByRef, EmbedIntoVocabulary, EmbeddedIntoVocabulary, ExtractFromVocabulary,
-		ExtractedFromVocabulary,
+		ExtractedFromVocabulary, TryExtractFromVocabulary,
 	},
 	Id, LexicalObjectRef, LexicalSubjectRef, Object, Quad, RdfDisplay, Term,
 };
@@ -138,6 +139,15 @@ impl<S, P, O> Triple<S, P, O> {
 	pub fn as_ref(&self) -> Triple<&S, &P, &O> {
 		Triple(&self.0, &self.1, &self.2)
 	}
+
+	/// Swaps the subject and object of the triple, reversing the edge it
+	/// represents.
+	///
+	/// Since the subject and object types are swapped, this is mostly useful
+	/// on homogeneous triples such as [`GrdfTriple`](crate::GrdfTriple).
+	pub fn reverse(self) -> Triple<O, P, S> {
+		Triple(self.2, self.1, self.0)
+	}
 }
 
 impl<'s, 'p, 'o, S, P, O> Triple<&'s S, &'p P, &'o O> {
@@ -187,6 +197,18 @@ impl<T> Triple<T, T, T> {
 	}
 }
 
+impl<I: PartialEq, B, L: PartialEq> Triple<Id<I, B>, I, Term<Id<I, B>, L>> {
+	/// Compares the two triples modulo blank node identifiers, considering
+	/// that every blank node identifier is equal to any other blank node
+	/// identifier.
+	///
+	/// This is a cheap approximation of isomorphism: isomorphic triples are
+	/// always ground-equal, but the converse is not true.
+	pub fn ground_eq(&self, other: &Self) -> bool {
+		self.0.ground_eq(&other.0) && self.1 == other.1 && self.2.ground_eq(&other.2)
+	}
+}
+
 impl LexicalTriple {
 	pub fn as_lexical_triple_ref(&self) -> LexicalTripleRef {
 		Triple(
@@ -197,6 +219,42 @@ impl LexicalTriple {
 	}
 }
 
+/// Error returned when parsing an N-Triples statement.
+///
+/// Each variant holds the byte offset at which the input is invalid.
+#[derive(Debug, thiserror::Error)]
+pub enum InvalidTriple {
+	#[error("invalid subject at byte offset {0}")]
+	Subject(usize),
+
+	#[error("invalid predicate at byte offset {0}")]
+	Predicate(usize),
+
+	#[error("invalid object at byte offset {0}")]
+	Object(usize),
+
+	#[error("expected `.` at byte offset {0}")]
+	End(usize),
+}
+
+impl FromStr for LexicalTriple {
+	type Err = InvalidTriple;
+
+	/// Parses a single N-Triples statement.
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let mut parser = Parser::new(s);
+		parser.skip_whitespace();
+		let subject = parser.id().map_err(InvalidTriple::Subject)?;
+		parser.skip_whitespace();
+		let predicate = parser.iri().map_err(InvalidTriple::Predicate)?;
+		parser.skip_whitespace();
+		let object = parser.term().map_err(InvalidTriple::Object)?;
+		parser.skip_whitespace();
+		parser.end().map_err(InvalidTriple::End)?;
+		Ok(Triple(subject, predicate, object))
+	}
+}
+
 impl<'a> LexicalTripleRef<'a> {
 	pub fn into_owned(self) -> LexicalTriple {
 		Triple(self.0.into_owned(), self.1.to_owned(), self.2.into_owned())
@@ -288,6 +346,47 @@ impl<
 	}
 }
 
+/// Error returned when calling [`try_extract_from_vocabulary`][1] on a
+/// [`Triple`].
+///
+/// [1]: TryExtractFromVocabulary::try_extract_from_vocabulary
+#[derive(Debug, thiserror::Error)]
+pub enum TripleExportFailed<S, P, O> {
+	#[error("invalid subject: {0}")]
+	Subject(S),
+
+	#[error("invalid predicate: {0}")]
+	Predicate(P),
+
+	#[error("invalid object: {0}")]
+	Object(O),
+}
+
+impl<
+		V,
+		S: TryExtractFromVocabulary<V>,
+		P: TryExtractFromVocabulary<V>,
+		O: TryExtractFromVocabulary<V>,
+	> TryExtractFromVocabulary<V> for Triple<S, P, O>
+{
+	type Extracted = Triple<S::Extracted, P::Extracted, O::Extracted>;
+	type Error = TripleExportFailed<S::Error, P::Error, O::Error>;
+
+	fn try_extract_from_vocabulary(self, vocabulary: &V) -> Result<Self::Extracted, Self::Error> {
+		Ok(Triple(
+			self.0
+				.try_extract_from_vocabulary(vocabulary)
+				.map_err(TripleExportFailed::Subject)?,
+			self.1
+				.try_extract_from_vocabulary(vocabulary)
+				.map_err(TripleExportFailed::Predicate)?,
+			self.2
+				.try_extract_from_vocabulary(vocabulary)
+				.map_err(TripleExportFailed::Object)?,
+		))
+	}
+}
+
 impl<S: RdfDisplay, P: RdfDisplay, O: RdfDisplay> fmt::Display for Triple<S, P, O> {
 	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
 		write!(
@@ -341,3 +440,76 @@ impl<S: RdfDisplayWithContext<V>, P: RdfDisplayWithContext<V>, O: RdfDisplayWith
 		)
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{BlankIdBuf, Literal, LiteralType, XSD_STRING};
+	use static_iref::iri;
+
+	#[test]
+	fn parse_n_triples() {
+		let triple: LexicalTriple = "<http://example.org/#a> <http://example.org/#b> \"c\" ."
+			.parse()
+			.unwrap();
+		let expected: LexicalTriple = Triple(
+			Id::Iri(iri!("http://example.org/#a").to_owned()),
+			iri!("http://example.org/#b").to_owned(),
+			Term::Literal(Literal::new(
+				"c".to_owned(),
+				LiteralType::Any(XSD_STRING.to_owned()),
+			)),
+		);
+		assert_eq!(triple, expected);
+
+		let triple: LexicalTriple = "_:b0 <http://example.org/#p> \"a\\\"b\\nc\\u00E9\"@en-US .\n"
+			.parse()
+			.unwrap();
+		let expected: Id = Id::Blank(BlankIdBuf::from_suffix("b0").unwrap());
+		assert_eq!(triple.0, expected);
+		let literal = triple.2.as_literal().unwrap();
+		assert_eq!(literal.value, "a\"b\nc\u{e9}");
+		assert_eq!(literal.lang_tag().unwrap().as_str(), "en-US");
+
+		let triple: LexicalTriple = "<http://example.org/#a> <http://example.org/#b> \"1\"^^<http://www.w3.org/2001/XMLSchema#integer> . # comment"
+			.parse()
+			.unwrap();
+		assert!(triple
+			.2
+			.as_literal()
+			.unwrap()
+			.type_
+			.is_iri(&iri!("http://www.w3.org/2001/XMLSchema#integer").to_owned()))
+	}
+
+	#[test]
+	fn parse_n_triples_round_trip() {
+		let input = "_:b0 <http://example.org/#p> _:b1 .";
+		let triple: LexicalTriple = input.parse().unwrap();
+		assert_eq!(format!("{triple} ."), input)
+	}
+
+	#[test]
+	fn parse_n_triples_errors() {
+		assert!(matches!(
+			"\"a\" <http://example.org/#p> _:b1 .".parse::<LexicalTriple>(),
+			Err(InvalidTriple::Subject(0))
+		));
+		assert!(matches!(
+			"_:b0 _:p _:b1 .".parse::<LexicalTriple>(),
+			Err(InvalidTriple::Predicate(5))
+		));
+		assert!(matches!(
+			"_:b0 <http://example.org/#p> \"a .".parse::<LexicalTriple>(),
+			Err(InvalidTriple::Object(29))
+		));
+		assert!(matches!(
+			"_:b0 <http://example.org/#p> _:b1".parse::<LexicalTriple>(),
+			Err(InvalidTriple::End(33))
+		));
+		assert!(matches!(
+			"_:b0 <http://example.org/#p> \"\\u+041\" .".parse::<LexicalTriple>(),
+			Err(InvalidTriple::Object(32))
+		))
+	}
+}
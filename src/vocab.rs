@@ -0,0 +1,68 @@
+//! Standard RDF, RDFS and XSD vocabularies.
+//!
+//! Each module re-exports the `&'static Iri` constants defined at the crate
+//! root (e.g. [`XSD_INTEGER`](crate::XSD_INTEGER)) under a shorter name, so
+//! they can be used without allocation:
+//!
+//! ```
+//! use rdf_types::{vocab::{rdf, rdfs}, Triple};
+//!
+//! let triple = Triple(rdfs::LABEL, rdf::TYPE, rdf::PROPERTY);
+//! ```
+
+/// RDF vocabulary (`http://www.w3.org/1999/02/22-rdf-syntax-ns#`).
+pub mod rdf {
+	pub use crate::RDF_ALT as ALT;
+	pub use crate::RDF_BAG as BAG;
+	pub use crate::RDF_FIRST as FIRST;
+	pub use crate::RDF_HTML as HTML;
+	pub use crate::RDF_JSON as JSON;
+	pub use crate::RDF_LANG_STRING as LANG_STRING;
+	pub use crate::RDF_LIST as LIST;
+	pub use crate::RDF_NIL as NIL;
+	pub use crate::RDF_OBJECT as OBJECT;
+	pub use crate::RDF_PREDICATE as PREDICATE;
+	pub use crate::RDF_PROPERTY as PROPERTY;
+	pub use crate::RDF_REST as REST;
+	pub use crate::RDF_SEQ as SEQ;
+	pub use crate::RDF_STATEMENT as STATEMENT;
+	pub use crate::RDF_SUBJECT as SUBJECT;
+	pub use crate::RDF_TYPE as TYPE;
+	pub use crate::RDF_VALUE as VALUE;
+	pub use crate::RDF_XML_LITERAL as XML_LITERAL;
+}
+
+/// RDF Schema vocabulary (`http://www.w3.org/2000/01/rdf-schema#`).
+pub mod rdfs {
+	pub use crate::RDFS_CLASS as CLASS;
+	pub use crate::RDFS_COMMENT as COMMENT;
+	pub use crate::RDFS_CONTAINER as CONTAINER;
+	pub use crate::RDFS_CONTAINER_MEMBERSHIP_PROPERTY as CONTAINER_MEMBERSHIP_PROPERTY;
+	pub use crate::RDFS_DATATYPE as DATATYPE;
+	pub use crate::RDFS_DOMAIN as DOMAIN;
+	pub use crate::RDFS_IS_DEFINED_BY as IS_DEFINED_BY;
+	pub use crate::RDFS_LABEL as LABEL;
+	pub use crate::RDFS_LITERAL as LITERAL;
+	pub use crate::RDFS_MEMBER as MEMBER;
+	pub use crate::RDFS_RANGE as RANGE;
+	pub use crate::RDFS_RESOURCE as RESOURCE;
+	pub use crate::RDFS_SEE_ALSO as SEE_ALSO;
+	pub use crate::RDFS_SUB_CLASS_OF as SUB_CLASS_OF;
+	pub use crate::RDFS_SUB_PROPERTY_OF as SUB_PROPERTY_OF;
+}
+
+/// XML Schema datatypes (`http://www.w3.org/2001/XMLSchema#`).
+pub mod xsd {
+	pub use crate::XSD_ANY_URI as ANY_URI;
+	pub use crate::XSD_BASE64_BINARY as BASE64_BINARY;
+	pub use crate::XSD_BOOLEAN as BOOLEAN;
+	pub use crate::XSD_DATE as DATE;
+	pub use crate::XSD_DATE_TIME as DATE_TIME;
+	pub use crate::XSD_DECIMAL as DECIMAL;
+	pub use crate::XSD_DOUBLE as DOUBLE;
+	pub use crate::XSD_FLOAT as FLOAT;
+	pub use crate::XSD_HEX_BINARY as HEX_BINARY;
+	pub use crate::XSD_INTEGER as INTEGER;
+	pub use crate::XSD_STRING as STRING;
+	pub use crate::XSD_TIME as TIME;
+}
@@ -0,0 +1,190 @@
+use std::collections::HashMap;
+
+use iref::{Iri, IriBuf};
+
+use super::VocabularyMut;
+use crate::{Id, LexicalQuad, Literal, LiteralType, Quad, Term};
+
+/// Quad whose components have been inserted into a vocabulary.
+type InsertedQuad<I, B, L> = Quad<Id<I, B>, I, Term<Id<I, B>, L>, Id<I, B>>;
+
+/// Inserts every component of the given quads into the vocabulary, including
+/// literal values and their types.
+///
+/// This is equivalent to calling [`Quad::insert_into`] on each quad, except
+/// that each distinct IRI of the batch is inserted into the vocabulary only
+/// once.
+///
+/// # Example
+///
+/// ```
+/// use rdf_types::{LexicalQuad, vocabulary::{insert_quads, IndexVocabulary}};
+///
+/// let quads: Vec<LexicalQuad> = vec![
+///   "_:a <http://example.org/p> _:b .".parse().unwrap(),
+///   "_:b <http://example.org/p> \"c\" .".parse().unwrap(),
+/// ];
+///
+/// let mut vocabulary: IndexVocabulary = IndexVocabulary::new();
+/// let inserted = insert_quads(&mut vocabulary, quads);
+/// assert_eq!(inserted[0].1, inserted[1].1)
+/// ```
+pub fn insert_quads<V: VocabularyMut>(
+	vocabulary: &mut V,
+	quads: impl IntoIterator<Item = LexicalQuad>,
+) -> Vec<InsertedQuad<V::Iri, V::BlankId, V::Literal>>
+where
+	V::Iri: Clone,
+{
+	let mut batch = Batch::new(vocabulary);
+	quads.into_iter().map(|quad| batch.quad(quad)).collect()
+}
+
+/// Inserts a copy of every component of the given quads into the vocabulary,
+/// including literal values and their types.
+///
+/// This is equivalent to calling [`Quad::inserted_into`] on each quad, except
+/// that each distinct IRI of the batch is inserted into the vocabulary only
+/// once.
+pub fn inserted_quads<'a, V: VocabularyMut>(
+	vocabulary: &mut V,
+	quads: impl IntoIterator<Item = &'a LexicalQuad>,
+) -> Vec<InsertedQuad<V::Iri, V::BlankId, V::Literal>>
+where
+	V::Iri: Clone,
+{
+	let mut batch = Batch::new(vocabulary);
+	quads
+		.into_iter()
+		.map(|Quad(s, p, o, g)| {
+			let o = match o {
+				Term::Id(id) => Term::Id(batch.id(id)),
+				Term::Literal(l) => Term::Literal(batch.literal(l.clone())),
+			};
+
+			Quad(
+				batch.id(s),
+				batch.iri(p),
+				o,
+				g.as_ref().map(|g| batch.id(g)),
+			)
+		})
+		.collect()
+}
+
+/// Vocabulary insertion batch, remembering the IRIs already inserted.
+struct Batch<'v, V: VocabularyMut> {
+	vocabulary: &'v mut V,
+	iris: HashMap<IriBuf, V::Iri>,
+}
+
+impl<'v, V: VocabularyMut> Batch<'v, V>
+where
+	V::Iri: Clone,
+{
+	fn new(vocabulary: &'v mut V) -> Self {
+		Self {
+			vocabulary,
+			iris: HashMap::new(),
+		}
+	}
+
+	fn iri(&mut self, iri: &Iri) -> V::Iri {
+		match self.iris.get(iri) {
+			Some(i) => i.clone(),
+			None => {
+				let i = self.vocabulary.insert(iri);
+				self.iris.insert(iri.to_owned(), i.clone());
+				i
+			}
+		}
+	}
+
+	fn owned_iri(&mut self, iri: IriBuf) -> V::Iri {
+		match self.iris.get(&iri) {
+			Some(i) => i.clone(),
+			None => {
+				let i = self.vocabulary.insert_owned(iri.clone());
+				self.iris.insert(iri, i.clone());
+				i
+			}
+		}
+	}
+
+	fn id(&mut self, id: &Id) -> Id<V::Iri, V::BlankId> {
+		match id {
+			Id::Iri(iri) => Id::Iri(self.iri(iri)),
+			Id::Blank(b) => Id::Blank(self.vocabulary.insert_blank_id(b)),
+		}
+	}
+
+	fn owned_id(&mut self, id: Id) -> Id<V::Iri, V::BlankId> {
+		match id {
+			Id::Iri(iri) => Id::Iri(self.owned_iri(iri)),
+			Id::Blank(b) => Id::Blank(self.vocabulary.insert_owned_blank_id(b)),
+		}
+	}
+
+	fn literal(&mut self, literal: Literal) -> V::Literal {
+		let type_ = match literal.type_ {
+			LiteralType::Any(ty) => LiteralType::Any(self.owned_iri(ty)),
+			LiteralType::LangString(tag) => LiteralType::LangString(tag),
+			LiteralType::DirLangString(tag, direction) => {
+				LiteralType::DirLangString(tag, direction)
+			}
+		};
+
+		self.vocabulary
+			.insert_owned_literal(Literal::new(literal.value, type_))
+	}
+
+	fn quad(
+		&mut self,
+		Quad(s, p, o, g): LexicalQuad,
+	) -> InsertedQuad<V::Iri, V::BlankId, V::Literal> {
+		let o = match o {
+			Term::Id(id) => Term::Id(self.owned_id(id)),
+			Term::Literal(l) => Term::Literal(self.literal(l)),
+		};
+
+		Quad(
+			self.owned_id(s),
+			self.owned_iri(p),
+			o,
+			g.map(|g| self.owned_id(g)),
+		)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::vocabulary::{IndexVocabulary, IriVocabulary};
+
+	#[test]
+	fn batch() {
+		let quads: Vec<LexicalQuad> = vec![
+			"<http://example.org/a> <http://example.org/p> _:b <http://example.org/g> ."
+				.parse()
+				.unwrap(),
+			"_:b <http://example.org/p> \"1\"^^<http://example.org/a> ."
+				.parse()
+				.unwrap(),
+		];
+
+		let mut vocabulary: IndexVocabulary = IndexVocabulary::new();
+		let inserted = inserted_quads(&mut vocabulary, &quads);
+		assert_eq!(inserted, insert_quads(&mut vocabulary, quads.clone()));
+		assert_eq!(inserted[0].1, inserted[1].1);
+
+		let expected: Vec<_> = quads
+			.iter()
+			.map(|quad| quad.inserted_into(&mut vocabulary))
+			.collect();
+		assert_eq!(inserted, expected);
+		assert_eq!(
+			vocabulary.iri(&inserted[0].1).unwrap().as_str(),
+			"http://example.org/p"
+		)
+	}
+}
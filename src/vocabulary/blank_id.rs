@@ -16,6 +16,43 @@ pub trait BlankIdVocabulary {
 
 	/// Returns the vocabulary id of the given blank node identifier, if any.
 	fn get_blank_id(&self, id: &BlankId) -> Option<Self::BlankId>;
+
+	/// Returns an iterator over the blank node identifiers associated to the
+	/// given ids.
+	fn resolve_blank_ids<'a>(&'a self, ids: &'a [Self::BlankId]) -> ResolveBlankIds<'a, Self> {
+		ResolveBlankIds {
+			vocabulary: self,
+			ids: ids.iter(),
+		}
+	}
+}
+
+/// Iterator over the blank node identifiers associated to a slice of ids.
+///
+/// Returned by [`BlankIdVocabulary::resolve_blank_ids`].
+pub struct ResolveBlankIds<'a, V: ?Sized + BlankIdVocabulary> {
+	vocabulary: &'a V,
+	ids: std::slice::Iter<'a, V::BlankId>,
+}
+
+impl<'a, V: ?Sized + BlankIdVocabulary> Iterator for ResolveBlankIds<'a, V> {
+	type Item = Option<&'a BlankId>;
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		self.ids.size_hint()
+	}
+
+	fn next(&mut self) -> Option<Self::Item> {
+		self.ids.next().map(|id| self.vocabulary.blank_id(id))
+	}
+}
+
+impl<'a, V: ?Sized + BlankIdVocabulary> ExactSizeIterator for ResolveBlankIds<'a, V> {}
+
+impl<'a, V: ?Sized + BlankIdVocabulary> DoubleEndedIterator for ResolveBlankIds<'a, V> {
+	fn next_back(&mut self) -> Option<Self::Item> {
+		self.ids.next_back().map(|id| self.vocabulary.blank_id(id))
+	}
 }
 
 impl<'a, V: BlankIdVocabulary> BlankIdVocabulary for &'a V {
@@ -58,6 +95,12 @@ pub trait BlankIdVocabularyMut: BlankIdVocabulary {
 	/// vocabulary id is created and the current one is returned.
 	fn insert_blank_id(&mut self, id: &BlankId) -> Self::BlankId;
 
+	/// Inserts an owned blank node identifier to the vocabulary and returns
+	/// its id.
+	///
+	/// Vocabularies storing blank node identifiers should override this
+	/// method to move the given buffer into their storage instead of copying
+	/// it.
 	fn insert_owned_blank_id(&mut self, id: BlankIdBuf) -> Self::BlankId {
 		self.insert_blank_id(id.as_blank_id_ref())
 	}
@@ -0,0 +1,253 @@
+use std::borrow::Borrow;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use iref::{Iri, IriBuf};
+
+use crate::vocabulary::{BlankIdVocabulary, BlankIdVocabularyMut, IriVocabulary, IriVocabularyMut};
+use crate::{BlankId, BlankIdBuf};
+
+/// IRI identifier in a [`HashMapVocabulary`].
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Debug)]
+pub struct IriKey {
+	index: usize,
+	generation: usize,
+}
+
+/// Blank node identifier in a [`HashMapVocabulary`].
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Debug)]
+pub struct BlankIdKey {
+	index: usize,
+	generation: usize,
+}
+
+/// Vocabulary storing IRIs and blank node identifiers in generational slots.
+///
+/// Contrarily to [`IndexVocabulary`](super::IndexVocabulary), entries can be
+/// removed using [`HashMapVocabulary::remove_iri`] and
+/// [`HashMapVocabulary::remove_blank_id`], and their storage is reused by
+/// later insertions.
+///
+/// # Invalidation
+///
+/// Removing an entry invalidates every outstanding identifier for it: the
+/// vocabulary resolves them to `None`, even if the same value is inserted
+/// again later, in which case it is given a new identifier.
+#[derive(Debug, Default, Clone)]
+pub struct HashMapVocabulary {
+	iris: Slots<IriBuf>,
+	blank_ids: Slots<BlankIdBuf>,
+}
+
+impl HashMapVocabulary {
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Returns the number of IRIs stored in this vocabulary.
+	pub fn iri_count(&self) -> usize {
+		self.iris.len()
+	}
+
+	/// Returns the number of blank node identifiers stored in this
+	/// vocabulary.
+	pub fn blank_id_count(&self) -> usize {
+		self.blank_ids.len()
+	}
+
+	/// Removes the given IRI from the vocabulary, invalidating `id`.
+	///
+	/// Returns the removed IRI, or `None` if `id` was already invalid.
+	pub fn remove_iri(&mut self, id: IriKey) -> Option<IriBuf> {
+		self.iris.remove(id.index, id.generation)
+	}
+
+	/// Removes the given blank node identifier from the vocabulary,
+	/// invalidating `id`.
+	///
+	/// Returns the removed blank node identifier, or `None` if `id` was
+	/// already invalid.
+	pub fn remove_blank_id(&mut self, id: BlankIdKey) -> Option<BlankIdBuf> {
+		self.blank_ids.remove(id.index, id.generation)
+	}
+}
+
+impl IriVocabulary for HashMapVocabulary {
+	type Iri = IriKey;
+
+	fn iri<'i>(&'i self, id: &'i IriKey) -> Option<&'i Iri> {
+		self.iris.get(id.index, id.generation).map(IriBuf::as_iri)
+	}
+
+	fn get(&self, iri: &Iri) -> Option<IriKey> {
+		self.iris
+			.find(iri)
+			.map(|(index, generation)| IriKey { index, generation })
+	}
+}
+
+impl IriVocabularyMut for HashMapVocabulary {
+	fn insert(&mut self, iri: &Iri) -> IriKey {
+		match self.get(iri) {
+			Some(key) => key,
+			None => self.insert_owned(iri.to_owned()),
+		}
+	}
+
+	fn insert_owned(&mut self, iri: IriBuf) -> IriKey {
+		let (index, generation) = self.iris.insert(iri);
+		IriKey { index, generation }
+	}
+}
+
+impl BlankIdVocabulary for HashMapVocabulary {
+	type BlankId = BlankIdKey;
+
+	fn blank_id<'b>(&'b self, id: &'b BlankIdKey) -> Option<&'b BlankId> {
+		self.blank_ids
+			.get(id.index, id.generation)
+			.map(BlankIdBuf::as_blank_id_ref)
+	}
+
+	fn get_blank_id(&self, blank_id: &BlankId) -> Option<BlankIdKey> {
+		self.blank_ids
+			.find(blank_id)
+			.map(|(index, generation)| BlankIdKey { index, generation })
+	}
+}
+
+impl BlankIdVocabularyMut for HashMapVocabulary {
+	fn insert_blank_id(&mut self, blank_id: &BlankId) -> BlankIdKey {
+		match self.get_blank_id(blank_id) {
+			Some(key) => key,
+			None => self.insert_owned_blank_id(blank_id.to_owned()),
+		}
+	}
+
+	fn insert_owned_blank_id(&mut self, blank_id: BlankIdBuf) -> BlankIdKey {
+		let (index, generation) = self.blank_ids.insert(blank_id);
+		BlankIdKey { index, generation }
+	}
+}
+
+#[derive(Debug, Clone)]
+struct Slot<T> {
+	generation: usize,
+	value: Option<T>,
+}
+
+/// Generational slots, with a reverse lookup table.
+#[derive(Debug, Clone)]
+struct Slots<T> {
+	slots: Vec<Slot<T>>,
+	free: Vec<usize>,
+	map: HashMap<T, usize>,
+}
+
+impl<T> Default for Slots<T> {
+	fn default() -> Self {
+		Self {
+			slots: Vec::new(),
+			free: Vec::new(),
+			map: HashMap::new(),
+		}
+	}
+}
+
+impl<T: Clone + Eq + Hash> Slots<T> {
+	fn len(&self) -> usize {
+		self.map.len()
+	}
+
+	fn get(&self, index: usize, generation: usize) -> Option<&T> {
+		self.slots
+			.get(index)
+			.filter(|slot| slot.generation == generation)
+			.and_then(|slot| slot.value.as_ref())
+	}
+
+	fn find<Q: ?Sized + Eq + Hash>(&self, value: &Q) -> Option<(usize, usize)>
+	where
+		T: Borrow<Q>,
+	{
+		self.map
+			.get(value)
+			.map(|&index| (index, self.slots[index].generation))
+	}
+
+	fn insert(&mut self, value: T) -> (usize, usize) {
+		if let Some(key) = self.find(&value) {
+			return key;
+		}
+
+		let index = match self.free.pop() {
+			Some(index) => {
+				self.slots[index].value = Some(value.clone());
+				index
+			}
+			None => {
+				self.slots.push(Slot {
+					generation: 0,
+					value: Some(value.clone()),
+				});
+				self.slots.len() - 1
+			}
+		};
+
+		self.map.insert(value, index);
+		(index, self.slots[index].generation)
+	}
+
+	fn remove(&mut self, index: usize, generation: usize) -> Option<T> {
+		let slot = self
+			.slots
+			.get_mut(index)
+			.filter(|slot| slot.generation == generation)?;
+		let value = slot.value.take()?;
+		slot.generation = slot.generation.wrapping_add(1);
+		self.map.remove(&value);
+		self.free.push(index);
+		Some(value)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use static_iref::iri;
+
+	#[test]
+	fn remove_iri() {
+		let mut vocabulary = HashMapVocabulary::new();
+		let a = vocabulary.insert(iri!("http://example.org/#a"));
+		assert_eq!(vocabulary.insert(iri!("http://example.org/#a")), a);
+		assert_eq!(vocabulary.iri(&a), Some(iri!("http://example.org/#a")));
+
+		assert_eq!(
+			vocabulary.remove_iri(a),
+			Some(iri!("http://example.org/#a").to_owned())
+		);
+		assert_eq!(vocabulary.remove_iri(a), None);
+		assert_eq!(vocabulary.iri(&a), None);
+		assert_eq!(vocabulary.get(iri!("http://example.org/#a")), None);
+		assert_eq!(vocabulary.iri_count(), 0);
+
+		let b = vocabulary.insert(iri!("http://example.org/#b"));
+		assert_ne!(a, b);
+		assert_eq!(vocabulary.iri(&a), None);
+		assert_eq!(vocabulary.iri(&b), Some(iri!("http://example.org/#b")));
+	}
+
+	#[test]
+	fn remove_blank_id() {
+		let mut vocabulary = HashMapVocabulary::new();
+		let a = vocabulary.insert_blank_id(BlankId::new("_:a").unwrap());
+		assert_eq!(vocabulary.blank_id_count(), 1);
+		assert!(vocabulary.remove_blank_id(a).is_some());
+		assert_eq!(vocabulary.blank_id(&a), None);
+
+		let b = vocabulary.insert_blank_id(BlankId::new("_:a").unwrap());
+		assert_ne!(a, b);
+		assert_eq!(vocabulary.blank_id(&b), Some(BlankId::new("_:a").unwrap()));
+	}
+}
@@ -4,8 +4,17 @@ use std::hash::Hash;
 
 /// Blank id index.
 #[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
 pub struct BlankIdIndex(usize);
 
+impl BlankIdIndex {
+	/// Returns the raw index value.
+	pub fn get(&self) -> usize {
+		self.0
+	}
+}
+
 impl From<usize> for BlankIdIndex {
 	fn from(i: usize) -> Self {
 		Self(i)
@@ -4,8 +4,17 @@ use std::hash::Hash;
 
 /// Iri index.
 #[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
 pub struct IriIndex(usize);
 
+impl IriIndex {
+	/// Returns the raw index value.
+	pub fn get(&self) -> usize {
+		self.0
+	}
+}
+
 impl From<usize> for IriIndex {
 	fn from(i: usize) -> Self {
 		Self(i)
@@ -109,6 +118,6 @@ impl<I, V: crate::vocabulary::IriVocabulary<Iri = IriOrIndex<I>>> crate::RdfDisp
 	for IriOrIndex<I>
 {
 	fn rdf_fmt_with(&self, vocabulary: &V, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-		write!(f, "<{}>", &vocabulary.iri(self).unwrap())
+		crate::RdfDisplay::rdf_fmt(vocabulary.iri(self).unwrap(), f)
 	}
 }
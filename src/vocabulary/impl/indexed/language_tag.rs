@@ -0,0 +1,24 @@
+/// Language tag index.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+pub struct LanguageTagIndex(usize);
+
+impl LanguageTagIndex {
+	/// Returns the raw index value.
+	pub fn get(&self) -> usize {
+		self.0
+	}
+}
+
+impl From<usize> for LanguageTagIndex {
+	fn from(i: usize) -> Self {
+		Self(i)
+	}
+}
+
+impl From<LanguageTagIndex> for usize {
+	fn from(value: LanguageTagIndex) -> Self {
+		value.0
+	}
+}
@@ -5,8 +5,17 @@ use crate::{Literal, LiteralRef};
 
 /// Literal index.
 #[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
 pub struct LiteralIndex(usize);
 
+impl LiteralIndex {
+	/// Returns the raw index value.
+	pub fn get(&self) -> usize {
+		self.0
+	}
+}
+
 impl From<usize> for LiteralIndex {
 	fn from(i: usize) -> Self {
 		Self(i)
@@ -2,36 +2,97 @@ use std::hash::Hash;
 use std::marker::PhantomData;
 
 use crate::vocabulary::{
-	BlankIdVocabulary, BlankIdVocabularyMut, IriVocabulary, IriVocabularyMut, LiteralVocabulary,
-	LiteralVocabularyMut,
+	BlankIdVocabulary, BlankIdVocabularyMut, IriVocabulary, IriVocabularyMut,
+	LanguageTagVocabulary, LanguageTagVocabularyMut, LiteralVocabulary, LiteralVocabularyMut,
 };
 use crate::{BlankId, BlankIdBuf, Literal, LiteralRef};
 use indexmap::IndexSet;
 use iref::{Iri, IriBuf};
+use langtag::{LangTag, LangTagBuf};
 
 mod blankid;
 mod iri;
+mod language_tag;
 mod literal;
+mod remap;
 
 pub use blankid::*;
 pub use iri::*;
+pub use language_tag::*;
 pub use literal::*;
+pub use remap::*;
 
-/// Vocabulary that stores IRIs and blank node identifiers
-/// with a unique index.
+/// Vocabulary that stores IRIs, blank node identifiers, literals and
+/// language tags with a unique index.
+///
+/// With the `serde` feature enabled, the vocabulary is serialized as the
+/// ordered list of its entries, so that deserializing it preserves every
+/// index. Deserialization fails if an entry appears twice, since that would
+/// shift the index of every following entry.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+	feature = "serde",
+	serde(bound(
+		serialize = "I: serde::Serialize",
+		deserialize = "I: serde::Deserialize<'de> + Eq + Hash"
+	))
+)]
 pub struct IndexVocabulary<I = IriIndex, B = BlankIdIndex, L = LiteralIndex> {
+	#[cfg_attr(
+		feature = "serde",
+		serde(rename = "iris", deserialize_with = "deserialize_unique")
+	)]
 	iri: IndexSet<IriBuf>,
+
+	#[cfg_attr(
+		feature = "serde",
+		serde(rename = "blank_ids", deserialize_with = "deserialize_unique")
+	)]
 	blank_id: IndexSet<BlankIdBuf>,
+
+	#[cfg_attr(
+		feature = "serde",
+		serde(rename = "literals", deserialize_with = "deserialize_unique")
+	)]
 	literal: IndexSet<Literal<I>>,
+
+	#[cfg_attr(
+		feature = "serde",
+		serde(rename = "language_tags", deserialize_with = "deserialize_unique")
+	)]
+	language_tag: IndexSet<LangTagBuf>,
+
+	#[cfg_attr(feature = "serde", serde(skip))]
 	bl: PhantomData<(B, L)>,
 }
 
+/// Deserializes a list of vocabulary entries, rejecting duplicates.
+#[cfg(feature = "serde")]
+fn deserialize_unique<'de, D, T>(deserializer: D) -> Result<IndexSet<T>, D::Error>
+where
+	D: serde::Deserializer<'de>,
+	T: serde::Deserialize<'de> + Eq + Hash,
+{
+	let entries: Vec<T> = serde::Deserialize::deserialize(deserializer)?;
+	let mut result = IndexSet::with_capacity(entries.len());
+	for (i, entry) in entries.into_iter().enumerate() {
+		if !result.insert(entry) {
+			return Err(serde::de::Error::custom(format!(
+				"duplicate vocabulary entry at index {i}"
+			)));
+		}
+	}
+
+	Ok(result)
+}
+
 impl<I, B, L> Default for IndexVocabulary<I, B, L> {
 	fn default() -> Self {
 		Self {
 			iri: IndexSet::new(),
 			blank_id: IndexSet::new(),
 			literal: IndexSet::new(),
+			language_tag: IndexSet::new(),
 			bl: PhantomData,
 		}
 	}
@@ -41,6 +102,184 @@ impl<I, B> IndexVocabulary<I, B> {
 	pub fn new() -> Self {
 		Self::default()
 	}
+
+	/// Creates a new vocabulary with enough capacity to store the given
+	/// number of IRIs and blank node identifiers without reallocating.
+	pub fn with_capacity(iris: usize, blank_ids: usize) -> Self {
+		Self {
+			iri: IndexSet::with_capacity(iris),
+			blank_id: IndexSet::with_capacity(blank_ids),
+			literal: IndexSet::new(),
+			language_tag: IndexSet::new(),
+			bl: PhantomData,
+		}
+	}
+}
+
+impl<I, B, L> IndexVocabulary<I, B, L> {
+	/// Reserves capacity for at least `iris` more IRIs and `blank_ids` more
+	/// blank node identifiers.
+	pub fn reserve(&mut self, iris: usize, blank_ids: usize) {
+		self.iri.reserve(iris);
+		self.blank_id.reserve(blank_ids)
+	}
+
+	/// Returns the number of IRIs, blank node identifiers, literals and
+	/// language tags stored in this vocabulary.
+	pub fn len(&self) -> usize {
+		self.iri.len() + self.blank_id.len() + self.literal.len() + self.language_tag.len()
+	}
+
+	/// Checks if this vocabulary is empty.
+	pub fn is_empty(&self) -> bool {
+		self.len() == 0
+	}
+
+	/// Returns the number of IRIs stored in this vocabulary.
+	pub fn iri_count(&self) -> usize {
+		self.iri.len()
+	}
+
+	/// Returns the number of blank node identifiers stored in this vocabulary.
+	pub fn blank_id_count(&self) -> usize {
+		self.blank_id.len()
+	}
+
+	/// Returns the number of literals stored in this vocabulary.
+	pub fn literal_count(&self) -> usize {
+		self.literal.len()
+	}
+
+	/// Returns the number of language tags stored in this vocabulary.
+	pub fn language_tag_count(&self) -> usize {
+		self.language_tag.len()
+	}
+
+	/// Returns an iterator over the stored IRIs and their index, in insertion
+	/// order.
+	pub fn iris(&self) -> impl Iterator<Item = (I, &Iri)>
+	where
+		I: From<usize>,
+	{
+		self.iri
+			.iter()
+			.enumerate()
+			.map(|(i, iri)| (i.into(), iri.as_iri()))
+	}
+
+	/// Returns an iterator over the stored blank node identifiers and their
+	/// index, in insertion order.
+	pub fn blank_ids(&self) -> impl Iterator<Item = (B, &BlankId)>
+	where
+		B: From<usize>,
+	{
+		self.blank_id
+			.iter()
+			.enumerate()
+			.map(|(i, blank_id)| (i.into(), blank_id.as_blank_id_ref()))
+	}
+
+	/// Returns an iterator over the stored literals and their index, in
+	/// insertion order.
+	pub fn literals(&self) -> impl Iterator<Item = (L, &Literal<I>)>
+	where
+		L: From<usize>,
+	{
+		self.literal
+			.iter()
+			.enumerate()
+			.map(|(i, literal)| (i.into(), literal))
+	}
+
+	/// Returns an iterator over the stored language tags and their index, in
+	/// insertion order.
+	pub fn language_tags(&self) -> impl Iterator<Item = (LanguageTagIndex, &LangTag)> {
+		self.language_tag
+			.iter()
+			.enumerate()
+			.map(|(i, tag)| (i.into(), tag.as_lang_tag()))
+	}
+
+	/// Returns the index of the given IRI, if it is stored in this vocabulary.
+	///
+	/// Contrarily to [`IriVocabulary::get`], this never returns a statically
+	/// known IRI identifier.
+	pub fn index_of(&self, iri: &Iri) -> Option<I>
+	where
+		I: From<usize>,
+	{
+		self.iri.get_index_of(iri).map(I::from)
+	}
+
+	/// Creates an IRI index from the given raw index, checking that it is
+	/// bound in this vocabulary.
+	pub fn checked_iri_index(&self, i: usize) -> Option<I>
+	where
+		I: From<usize>,
+	{
+		(i < self.iri.len()).then(|| i.into())
+	}
+
+	/// Creates a blank node identifier index from the given raw index,
+	/// checking that it is bound in this vocabulary.
+	pub fn checked_blank_id_index(&self, i: usize) -> Option<B>
+	where
+		B: From<usize>,
+	{
+		(i < self.blank_id.len()).then(|| i.into())
+	}
+
+	/// Creates a literal index from the given raw index, checking that it is
+	/// bound in this vocabulary.
+	pub fn checked_literal_index(&self, i: usize) -> Option<L>
+	where
+		L: From<usize>,
+	{
+		(i < self.literal.len()).then(|| i.into())
+	}
+
+	/// Creates a language tag index from the given raw index, checking that
+	/// it is bound in this vocabulary.
+	pub fn checked_language_tag_index(&self, i: usize) -> Option<LanguageTagIndex> {
+		(i < self.language_tag.len()).then(|| i.into())
+	}
+}
+
+impl<I: Remap + Eq + Hash, B, L> IndexVocabulary<I, B, L> {
+	/// Inserts every IRI, blank node identifier, literal and language tag of
+	/// the given vocabulary into this one.
+	///
+	/// Returns the remapping translating the indexes of `other` into the
+	/// corresponding indexes of this vocabulary.
+	pub fn merge(&mut self, other: Self) -> IndexRemapping {
+		let mut remapping = IndexRemapping {
+			iris: other
+				.iri
+				.into_iter()
+				.map(|iri| self.iri.insert_full(iri).0)
+				.collect(),
+			blank_ids: other
+				.blank_id
+				.into_iter()
+				.map(|blank_id| self.blank_id.insert_full(blank_id).0)
+				.collect(),
+			literals: Vec::new(),
+			language_tags: other
+				.language_tag
+				.into_iter()
+				.map(|tag| self.language_tag.insert_full(tag).0)
+				.collect(),
+		};
+
+		let literals = other
+			.literal
+			.into_iter()
+			.map(|literal| self.literal.insert_full(literal.remap(&remapping)).0)
+			.collect();
+
+		remapping.literals = literals;
+		remapping
+	}
 }
 
 impl<I: IndexedIri, B, L> IriVocabulary for IndexVocabulary<I, B, L> {
@@ -56,7 +295,7 @@ impl<I: IndexedIri, B, L> IriVocabulary for IndexVocabulary<I, B, L> {
 	fn get(&self, iri: &Iri) -> Option<I> {
 		match I::try_from(iri) {
 			Ok(id) => Some(id),
-			Err(_) => self.iri.get_index_of(&iri.to_owned()).map(I::from),
+			Err(_) => self.iri.get_index_of(iri).map(I::from),
 		}
 	}
 }
@@ -65,7 +304,10 @@ impl<I: IndexedIri, B, L> IriVocabularyMut for IndexVocabulary<I, B, L> {
 	fn insert(&mut self, iri: &Iri) -> I {
 		match I::try_from(iri) {
 			Ok(id) => id,
-			Err(_) => self.iri.insert_full(iri.to_owned()).0.into(),
+			Err(_) => match self.iri.get_index_of(iri) {
+				Some(i) => i.into(),
+				None => self.iri.insert_full(iri.to_owned()).0.into(),
+			},
 		}
 	}
 
@@ -78,6 +320,37 @@ impl<I: IndexedIri, B, L> IriVocabularyMut for IndexVocabulary<I, B, L> {
 	}
 }
 
+impl<I: IndexedIri, B, L> IndexVocabulary<I, B, L> {
+	/// Creates a new vocabulary from the given IRIs.
+	///
+	/// Returns the vocabulary along with the index assigned to each IRI, in
+	/// order.
+	pub fn from_iris(iris: impl IntoIterator<Item = IriBuf>) -> (Self, Vec<I>) {
+		let mut vocabulary = Self::default();
+		let indexes = iris
+			.into_iter()
+			.map(|iri| vocabulary.insert_owned(iri))
+			.collect();
+		(vocabulary, indexes)
+	}
+}
+
+impl<I: IndexedIri, B, L> Extend<IriBuf> for IndexVocabulary<I, B, L> {
+	fn extend<T: IntoIterator<Item = IriBuf>>(&mut self, iter: T) {
+		for iri in iter {
+			self.insert_owned(iri);
+		}
+	}
+}
+
+impl<I: IndexedIri, B, L> FromIterator<IriBuf> for IndexVocabulary<I, B, L> {
+	fn from_iter<T: IntoIterator<Item = IriBuf>>(iter: T) -> Self {
+		let mut vocabulary = Self::default();
+		vocabulary.extend(iter);
+		vocabulary
+	}
+}
+
 impl<I, B: IndexedBlankId, L> BlankIdVocabulary for IndexVocabulary<I, B, L> {
 	type BlankId = B;
 
@@ -91,10 +364,7 @@ impl<I, B: IndexedBlankId, L> BlankIdVocabulary for IndexVocabulary<I, B, L> {
 	fn get_blank_id(&self, blank_id: &BlankId) -> Option<B> {
 		match B::try_from(blank_id) {
 			Ok(id) => Some(id),
-			Err(_) => self
-				.blank_id
-				.get_index_of(&blank_id.to_owned())
-				.map(B::from),
+			Err(_) => self.blank_id.get_index_of(blank_id).map(B::from),
 		}
 	}
 }
@@ -103,7 +373,10 @@ impl<I, B: IndexedBlankId, L> BlankIdVocabularyMut for IndexVocabulary<I, B, L>
 	fn insert_blank_id(&mut self, blank_id: &BlankId) -> Self::BlankId {
 		match B::try_from(blank_id) {
 			Ok(id) => id,
-			Err(_) => self.blank_id.insert_full(blank_id.to_owned()).0.into(),
+			Err(_) => match self.blank_id.get_index_of(blank_id) {
+				Some(i) => i.into(),
+				None => self.blank_id.insert_full(blank_id.to_owned()).0.into(),
+			},
 		}
 	}
 
@@ -116,6 +389,48 @@ impl<I, B: IndexedBlankId, L> BlankIdVocabularyMut for IndexVocabulary<I, B, L>
 	}
 }
 
+impl<I, B: IndexedBlankId, L> Extend<BlankIdBuf> for IndexVocabulary<I, B, L> {
+	fn extend<T: IntoIterator<Item = BlankIdBuf>>(&mut self, iter: T) {
+		for blank_id in iter {
+			self.insert_owned_blank_id(blank_id);
+		}
+	}
+}
+
+impl<I, B: IndexedBlankId, L> FromIterator<BlankIdBuf> for IndexVocabulary<I, B, L> {
+	fn from_iter<T: IntoIterator<Item = BlankIdBuf>>(iter: T) -> Self {
+		let mut vocabulary = Self::default();
+		vocabulary.extend(iter);
+		vocabulary
+	}
+}
+
+impl<I, B, L> LanguageTagVocabulary for IndexVocabulary<I, B, L> {
+	type LanguageTag = LanguageTagIndex;
+
+	fn language_tag<'l>(&'l self, id: &'l LanguageTagIndex) -> Option<&'l LangTag> {
+		self.language_tag
+			.get_index(usize::from(*id))
+			.map(LangTagBuf::as_lang_tag)
+	}
+
+	fn get_language_tag(&self, tag: &LangTag) -> Option<LanguageTagIndex> {
+		self.language_tag
+			.get_index_of(&tag.to_owned())
+			.map(LanguageTagIndex::from)
+	}
+}
+
+impl<I, B, L> LanguageTagVocabularyMut for IndexVocabulary<I, B, L> {
+	fn insert_language_tag(&mut self, tag: &LangTag) -> LanguageTagIndex {
+		self.insert_owned_language_tag(tag.to_owned())
+	}
+
+	fn insert_owned_language_tag(&mut self, tag: LangTagBuf) -> LanguageTagIndex {
+		self.language_tag.insert_full(tag).0.into()
+	}
+}
+
 impl<I: Clone + IndexedIri + Eq + Hash, B, L: IndexedLiteral<I>> LiteralVocabulary
 	for IndexVocabulary<I, B, L>
 {
@@ -166,3 +481,247 @@ impl<I: IndexedIri + Clone + Eq + Hash, B, L: IndexedLiteral<I>> LiteralVocabula
 		}
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::vocabulary::{EmbedIntoVocabulary, ExtractFromVocabulary};
+	use crate::{Id, LiteralType, LiteralTypeRef, Quad, Term, VocabularyQuad};
+	use iref::IriRef;
+	use static_iref::iri;
+
+	#[cfg(feature = "serde")]
+	#[test]
+	fn serde_round_trip() {
+		let mut vocabulary: IndexVocabulary = IndexVocabulary::new();
+		let a = vocabulary.insert(iri!("http://example.org/#a"));
+		let b = vocabulary.insert(iri!("http://example.org/#b"));
+		let c = vocabulary.insert_blank_id(BlankId::new("_:c").unwrap());
+		let l = vocabulary.insert_owned_literal(Literal::new("v".to_owned(), LiteralType::Any(b)));
+
+		let json = serde_json::to_string(&vocabulary).unwrap();
+		let deserialized: IndexVocabulary = serde_json::from_str(&json).unwrap();
+		assert_eq!(deserialized.iri(&a).unwrap(), vocabulary.iri(&a).unwrap());
+		assert_eq!(deserialized.iri(&b).unwrap(), vocabulary.iri(&b).unwrap());
+		assert_eq!(
+			deserialized.blank_id(&c).unwrap(),
+			vocabulary.blank_id(&c).unwrap()
+		);
+		assert_eq!(
+			deserialized.literal(&l).unwrap(),
+			vocabulary.literal(&l).unwrap()
+		);
+
+		let duplicate = json.replace("http://example.org/#b", "http://example.org/#a");
+		assert!(serde_json::from_str::<IndexVocabulary>(&duplicate).is_err())
+	}
+
+	#[test]
+	fn checked_indexes() {
+		let mut vocabulary: IndexVocabulary = IndexVocabulary::new();
+		let a = vocabulary.insert(iri!("http://example.org/#a"));
+		let b = vocabulary.insert_blank_id(BlankId::new("_:b").unwrap());
+
+		assert_eq!(vocabulary.index_of(iri!("http://example.org/#a")), Some(a));
+		assert_eq!(vocabulary.index_of(iri!("http://example.org/#b")), None);
+		assert_eq!(vocabulary.checked_iri_index(a.get()), Some(a));
+		assert_eq!(vocabulary.checked_iri_index(1), None);
+		assert_eq!(vocabulary.checked_blank_id_index(b.get()), Some(b));
+		assert_eq!(vocabulary.checked_literal_index(0), None)
+	}
+
+	#[test]
+	fn from_iterators() {
+		let a = iri!("http://example.org/#a").to_owned();
+		let b = iri!("http://example.org/#b").to_owned();
+
+		let (mut vocabulary, indexes): (IndexVocabulary, _) =
+			IndexVocabulary::from_iris([a.clone(), b.clone(), a.clone()]);
+		assert_eq!(indexes.len(), 3);
+		assert_eq!(indexes[0], indexes[2]);
+		assert_eq!(vocabulary.iri(&indexes[1]).unwrap().as_str(), b.as_str());
+		assert_eq!(vocabulary.iri_count(), 2);
+
+		let blank_id = BlankIdBuf::new("_:b").unwrap();
+		vocabulary.extend([blank_id.clone()]);
+		assert!(vocabulary.get_blank_id(&blank_id).is_some());
+
+		let vocabulary: IndexVocabulary = [a, b].into_iter().collect();
+		assert_eq!(vocabulary.iri_count(), 2)
+	}
+
+	#[test]
+	fn insert_absolute() {
+		let mut vocabulary: IndexVocabulary = IndexVocabulary::new();
+		let i = vocabulary
+			.insert_absolute(IriRef::new("http://example.org/#a").unwrap())
+			.unwrap();
+		assert_eq!(
+			vocabulary.iri(&i).unwrap().as_str(),
+			"http://example.org/#a"
+		);
+
+		let relative = IriRef::new("../a").unwrap();
+		assert_eq!(
+			vocabulary.insert_absolute(relative).unwrap_err().0.as_str(),
+			"../a"
+		);
+		assert_eq!(vocabulary.iri_count(), 1)
+	}
+
+	#[test]
+	fn insert_owned() {
+		let mut vocabulary: IndexVocabulary = IndexVocabulary::new();
+		let iri = iri!("http://example.org/#a").to_owned();
+		let ptr = iri.as_str().as_ptr();
+
+		let a = vocabulary.insert_owned(iri);
+		assert_eq!(vocabulary.iri(&a).unwrap().as_str().as_ptr(), ptr);
+		assert_eq!(vocabulary.insert(iri!("http://example.org/#a")), a);
+		assert_eq!(
+			vocabulary.insert_owned(iri!("http://example.org/#a").to_owned()),
+			a
+		);
+		assert_eq!(vocabulary.iri_count(), 1)
+	}
+
+	#[test]
+	fn insert_owned_blank_id() {
+		let mut vocabulary: IndexVocabulary = IndexVocabulary::new();
+		let mut generator = crate::generator::Blank::new_with_prefix(
+			"a_rather_long_blank_node_identifier_prefix".to_owned(),
+		);
+
+		let Id::Blank(b) = crate::Generator::next(&mut generator, &mut vocabulary) else {
+			panic!("expected a blank node identifier")
+		};
+		assert_eq!(
+			vocabulary.blank_id(&b).unwrap().as_str(),
+			"_:a_rather_long_blank_node_identifier_prefix0"
+		);
+
+		let blank_id =
+			BlankIdBuf::from_suffix("another_rather_long_blank_node_identifier").unwrap();
+		let ptr = blank_id.as_str().as_ptr();
+		let b = vocabulary.insert_owned_blank_id(blank_id);
+		assert_eq!(vocabulary.blank_id(&b).unwrap().as_str().as_ptr(), ptr);
+		assert_eq!(
+			vocabulary.insert_blank_id(
+				BlankId::new("_:another_rather_long_blank_node_identifier").unwrap()
+			),
+			b
+		)
+	}
+
+	#[test]
+	fn resolve_ids() {
+		let mut vocabulary: IndexVocabulary = IndexVocabulary::new();
+		let iris = [
+			vocabulary.insert(iri!("http://example.org/#a")),
+			vocabulary.insert(iri!("http://example.org/#b")),
+		];
+		let blank_ids = [vocabulary.insert_blank_id(BlankId::new("_:b").unwrap())];
+
+		let resolved: Vec<_> = vocabulary
+			.resolve_iris(&iris)
+			.map(|iri| iri.unwrap().as_str())
+			.collect();
+		assert_eq!(resolved, ["http://example.org/#a", "http://example.org/#b"]);
+		assert_eq!(vocabulary.resolve_iris(&iris).len(), 2);
+		assert_eq!(
+			vocabulary
+				.resolve_blank_ids(&blank_ids)
+				.next()
+				.unwrap()
+				.unwrap()
+				.as_str(),
+			"_:b"
+		)
+	}
+
+	#[test]
+	fn merge() {
+		let mut a: IndexVocabulary = IndexVocabulary::new();
+		a.insert(iri!("http://example.org/#a"));
+		a.insert_blank_id(BlankId::new("_:a").unwrap());
+
+		let mut b: IndexVocabulary = IndexVocabulary::new();
+		let p = b.insert(iri!("http://example.org/#p"));
+		let s = b.insert_blank_id(BlankId::new("_:a").unwrap());
+		let ty = b.insert(iri!("http://example.org/#type"));
+		let o = b.insert_owned_literal(Literal::new("o".to_owned(), LiteralType::Any(ty)));
+
+		let quad: VocabularyQuad<IriIndex, BlankIdIndex, LiteralIndex> =
+			Quad(Id::Blank(s), p, Term::Literal(o), None);
+
+		let remapping = a.merge(b);
+		let quad = remapping.apply(quad);
+
+		assert_eq!(
+			quad.0,
+			Id::Blank(a.get_blank_id(BlankId::new("_:a").unwrap()).unwrap())
+		);
+		assert_eq!(a.iri(&quad.1).unwrap(), iri!("http://example.org/#p"));
+
+		let literal = a.literal(quad.2.as_literal().unwrap()).unwrap();
+		assert_eq!(literal.value, "o");
+		let ty = a.get(iri!("http://example.org/#type")).unwrap();
+		assert_eq!(literal.type_, LiteralTypeRef::Any(&ty))
+	}
+
+	#[test]
+	fn iterate() {
+		let mut vocabulary: IndexVocabulary = IndexVocabulary::new();
+		let a = vocabulary.insert(iri!("http://example.org/#a"));
+		let b = vocabulary.insert(iri!("http://example.org/#b"));
+		vocabulary.insert(iri!("http://example.org/#a"));
+		let c = vocabulary.insert_blank_id(BlankId::new("_:c").unwrap());
+
+		assert_eq!(vocabulary.iri_count(), 2);
+		assert_eq!(vocabulary.blank_id_count(), 1);
+		assert_eq!(vocabulary.literal_count(), 0);
+		assert_eq!(vocabulary.len(), 3);
+
+		let iris: Vec<_> = vocabulary.iris().collect();
+		assert_eq!(
+			iris,
+			[
+				(a, iri!("http://example.org/#a")),
+				(b, iri!("http://example.org/#b"))
+			]
+		);
+
+		let blank_ids: Vec<_> = vocabulary.blank_ids().collect();
+		assert_eq!(blank_ids, [(c, BlankId::new("_:c").unwrap())])
+	}
+
+	#[test]
+	fn literal_round_trip() {
+		let mut vocabulary: IndexVocabulary = IndexVocabulary::new();
+		let term: Term = Term::Literal(Literal::new(
+			"value".to_owned(),
+			LiteralType::Any(iri!("http://example.org/#type").to_owned()),
+		));
+
+		let embedded = term.clone().embed_into_vocabulary(&mut vocabulary);
+		assert_eq!(vocabulary.literal_count(), 1);
+		assert_eq!(vocabulary.iri_count(), 1);
+		assert_eq!(embedded.extract_from_vocabulary(&vocabulary), term)
+	}
+
+	#[test]
+	fn language_tags() {
+		let mut vocabulary: IndexVocabulary = IndexVocabulary::new();
+		let en = LangTagBuf::new("en".to_owned()).unwrap();
+		let fr = LangTagBuf::new("fr".to_owned()).unwrap();
+
+		let a = vocabulary.insert_owned_language_tag(en.clone());
+		let b = vocabulary.insert_language_tag(&fr);
+		assert_eq!(vocabulary.insert_language_tag(&en), a);
+		assert_eq!(vocabulary.language_tag_count(), 2);
+
+		assert_eq!(vocabulary.language_tag(&b), Some(fr.as_lang_tag()));
+		assert_eq!(vocabulary.get_language_tag(&en), Some(a));
+		assert_eq!(vocabulary.owned_language_tag(a), Ok(en))
+	}
+}
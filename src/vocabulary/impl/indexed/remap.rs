@@ -0,0 +1,168 @@
+use crate::{Id, Literal, LiteralType, Quad, Term, Triple};
+
+use super::{
+	BlankIdIndex, BlankIdOrIndex, IriIndex, IriOrIndex, LanguageTagIndex, LiteralIndex,
+	LiteralOrIndex,
+};
+
+/// Index remapping.
+///
+/// Translates the indexes of a vocabulary merged into another, using
+/// [`IndexVocabulary::merge`](super::IndexVocabulary::merge), into the
+/// corresponding indexes of the destination vocabulary.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct IndexRemapping {
+	pub(crate) iris: Vec<usize>,
+	pub(crate) blank_ids: Vec<usize>,
+	pub(crate) literals: Vec<usize>,
+	pub(crate) language_tags: Vec<usize>,
+}
+
+impl IndexRemapping {
+	/// Returns the new index of the given IRI index, if any.
+	pub fn iri_index(&self, i: usize) -> Option<usize> {
+		self.iris.get(i).copied()
+	}
+
+	/// Returns the new index of the given blank node identifier index, if any.
+	pub fn blank_id_index(&self, i: usize) -> Option<usize> {
+		self.blank_ids.get(i).copied()
+	}
+
+	/// Returns the new index of the given literal index, if any.
+	pub fn literal_index(&self, i: usize) -> Option<usize> {
+		self.literals.get(i).copied()
+	}
+
+	/// Returns the new index of the given language tag index, if any.
+	pub fn language_tag_index(&self, i: usize) -> Option<usize> {
+		self.language_tags.get(i).copied()
+	}
+
+	/// Rewrites the indexes of the given value.
+	///
+	/// # Panics
+	///
+	/// Panics if the value contains an index that does not belong to the
+	/// merged vocabulary.
+	pub fn apply<T: Remap>(&self, value: T) -> T {
+		value.remap(self)
+	}
+}
+
+/// Value whose indexes can be rewritten using an [`IndexRemapping`].
+pub trait Remap {
+	/// Rewrites the indexes of this value.
+	fn remap(self, remapping: &IndexRemapping) -> Self;
+}
+
+impl Remap for IriIndex {
+	fn remap(self, remapping: &IndexRemapping) -> Self {
+		remapping.iris[usize::from(self)].into()
+	}
+}
+
+impl Remap for BlankIdIndex {
+	fn remap(self, remapping: &IndexRemapping) -> Self {
+		remapping.blank_ids[usize::from(self)].into()
+	}
+}
+
+impl Remap for LiteralIndex {
+	fn remap(self, remapping: &IndexRemapping) -> Self {
+		remapping.literals[usize::from(self)].into()
+	}
+}
+
+impl Remap for LanguageTagIndex {
+	fn remap(self, remapping: &IndexRemapping) -> Self {
+		remapping.language_tags[usize::from(self)].into()
+	}
+}
+
+impl<I> Remap for IriOrIndex<I> {
+	fn remap(self, remapping: &IndexRemapping) -> Self {
+		match self {
+			Self::Index(i) => Self::Index(remapping.iris[i]),
+			Self::Iri(iri) => Self::Iri(iri),
+		}
+	}
+}
+
+impl<B> Remap for BlankIdOrIndex<B> {
+	fn remap(self, remapping: &IndexRemapping) -> Self {
+		match self {
+			Self::Index(i) => Self::Index(remapping.blank_ids[i]),
+			Self::BlankId(b) => Self::BlankId(b),
+		}
+	}
+}
+
+impl<L> Remap for LiteralOrIndex<L> {
+	fn remap(self, remapping: &IndexRemapping) -> Self {
+		match self {
+			Self::Index(i) => Self::Index(remapping.literals[i]),
+			Self::Literal(l) => Self::Literal(l),
+		}
+	}
+}
+
+impl<I: Remap> Remap for LiteralType<I> {
+	fn remap(self, remapping: &IndexRemapping) -> Self {
+		match self {
+			Self::Any(i) => Self::Any(i.remap(remapping)),
+			other => other,
+		}
+	}
+}
+
+impl<I: Remap> Remap for Literal<I> {
+	fn remap(self, remapping: &IndexRemapping) -> Self {
+		Self::new(self.value, self.type_.remap(remapping))
+	}
+}
+
+impl<I: Remap, B: Remap> Remap for Id<I, B> {
+	fn remap(self, remapping: &IndexRemapping) -> Self {
+		match self {
+			Self::Iri(i) => Self::Iri(i.remap(remapping)),
+			Self::Blank(b) => Self::Blank(b.remap(remapping)),
+		}
+	}
+}
+
+impl<I: Remap, L: Remap> Remap for Term<I, L> {
+	fn remap(self, remapping: &IndexRemapping) -> Self {
+		match self {
+			Self::Id(id) => Self::Id(id.remap(remapping)),
+			Self::Literal(l) => Self::Literal(l.remap(remapping)),
+		}
+	}
+}
+
+impl<T: Remap> Remap for Option<T> {
+	fn remap(self, remapping: &IndexRemapping) -> Self {
+		self.map(|t| t.remap(remapping))
+	}
+}
+
+impl<S: Remap, P: Remap, O: Remap> Remap for Triple<S, P, O> {
+	fn remap(self, remapping: &IndexRemapping) -> Self {
+		Triple(
+			self.0.remap(remapping),
+			self.1.remap(remapping),
+			self.2.remap(remapping),
+		)
+	}
+}
+
+impl<S: Remap, P: Remap, O: Remap, G: Remap> Remap for Quad<S, P, O, G> {
+	fn remap(self, remapping: &IndexRemapping) -> Self {
+		Quad(
+			self.0.remap(remapping),
+			self.1.remap(remapping),
+			self.2.remap(remapping),
+			self.3.remap(remapping),
+		)
+	}
+}
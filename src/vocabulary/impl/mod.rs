@@ -1,7 +1,11 @@
+mod hash_map;
 mod indexed;
 mod none;
 mod scoped;
+mod shared;
 
+pub use hash_map::*;
 pub use indexed::*;
 pub use none::*;
 pub use scoped::*;
+pub use shared::*;
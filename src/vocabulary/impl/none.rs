@@ -3,11 +3,12 @@ use std::ptr::addr_of_mut;
 use crate::{
 	vocabulary::{
 		BlankIdVocabulary, BlankIdVocabularyMut, IriVocabulary, IriVocabularyMut,
-		LiteralVocabulary, LiteralVocabularyMut,
+		LanguageTagVocabulary, LanguageTagVocabularyMut, LiteralVocabulary, LiteralVocabularyMut,
 	},
 	BlankId, BlankIdBuf, Literal, LiteralRef,
 };
 use iref::{Iri, IriBuf};
+use langtag::{LangTag, LangTagBuf};
 
 /// No vocabulary.
 ///
@@ -106,3 +107,29 @@ impl LiteralVocabularyMut for NoVocabulary {
 		value
 	}
 }
+
+impl LanguageTagVocabulary for NoVocabulary {
+	type LanguageTag = LangTagBuf;
+
+	fn language_tag<'l>(&'l self, id: &'l LangTagBuf) -> Option<&'l LangTag> {
+		Some(id.as_lang_tag())
+	}
+
+	fn owned_language_tag(&self, id: Self::LanguageTag) -> Result<LangTagBuf, Self::LanguageTag> {
+		Ok(id)
+	}
+
+	fn get_language_tag(&self, tag: &LangTag) -> Option<LangTagBuf> {
+		Some(tag.to_owned())
+	}
+}
+
+impl LanguageTagVocabularyMut for NoVocabulary {
+	fn insert_language_tag(&mut self, tag: &LangTag) -> LangTagBuf {
+		tag.to_owned()
+	}
+
+	fn insert_owned_language_tag(&mut self, tag: LangTagBuf) -> Self::LanguageTag {
+		tag
+	}
+}
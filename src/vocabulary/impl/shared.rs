@@ -0,0 +1,134 @@
+use std::{ops::Deref, sync::Arc};
+
+use crate::{
+	vocabulary::{BlankIdVocabulary, IriVocabulary, LanguageTagVocabulary, LiteralVocabulary},
+	BlankId, BlankIdBuf, Literal, LiteralRef,
+};
+use iref::{Iri, IriBuf};
+use langtag::{LangTag, LangTagBuf};
+
+/// Shared, read-only vocabulary.
+///
+/// Wraps a vocabulary behind an [`Arc`] so that it can be cheaply cloned and
+/// passed around along with the terms it resolves, outliving the original
+/// owner of the vocabulary.
+///
+/// # Example
+///
+/// ```
+/// use rdf_types::vocabulary::{IndexVocabulary, IriVocabulary, IriVocabularyMut, SharedVocabulary};
+/// use static_iref::iri;
+///
+/// let mut vocabulary: IndexVocabulary = IndexVocabulary::new();
+/// let i = vocabulary.insert(iri!("http://example.org/"));
+///
+/// let shared = SharedVocabulary::new(vocabulary);
+/// let copy = shared.clone();
+/// drop(shared);
+///
+/// assert_eq!(copy.iri(&i).unwrap(), "http://example.org/")
+/// ```
+#[derive(Debug, Default)]
+pub struct SharedVocabulary<V>(Arc<V>);
+
+impl<V> SharedVocabulary<V> {
+	/// Shares the given vocabulary.
+	pub fn new(vocabulary: V) -> Self {
+		Self(Arc::new(vocabulary))
+	}
+
+	/// Returns the shared pointer to the vocabulary.
+	pub fn into_arc(self) -> Arc<V> {
+		self.0
+	}
+}
+
+impl<V> Clone for SharedVocabulary<V> {
+	fn clone(&self) -> Self {
+		Self(self.0.clone())
+	}
+}
+
+impl<V> From<V> for SharedVocabulary<V> {
+	fn from(value: V) -> Self {
+		Self::new(value)
+	}
+}
+
+impl<V> From<Arc<V>> for SharedVocabulary<V> {
+	fn from(value: Arc<V>) -> Self {
+		Self(value)
+	}
+}
+
+impl<V> Deref for SharedVocabulary<V> {
+	type Target = V;
+
+	fn deref(&self) -> &V {
+		&self.0
+	}
+}
+
+impl<V: IriVocabulary> IriVocabulary for SharedVocabulary<V> {
+	type Iri = V::Iri;
+
+	fn iri<'i>(&'i self, id: &'i Self::Iri) -> Option<&'i Iri> {
+		self.0.iri(id)
+	}
+
+	fn owned_iri(&self, id: Self::Iri) -> Result<IriBuf, Self::Iri> {
+		self.0.owned_iri(id)
+	}
+
+	fn get(&self, iri: &Iri) -> Option<Self::Iri> {
+		self.0.get(iri)
+	}
+}
+
+impl<V: BlankIdVocabulary> BlankIdVocabulary for SharedVocabulary<V> {
+	type BlankId = V::BlankId;
+
+	fn blank_id<'b>(&'b self, id: &'b Self::BlankId) -> Option<&'b BlankId> {
+		self.0.blank_id(id)
+	}
+
+	fn owned_blank_id(&self, id: Self::BlankId) -> Result<BlankIdBuf, Self::BlankId> {
+		self.0.owned_blank_id(id)
+	}
+
+	fn get_blank_id(&self, id: &BlankId) -> Option<Self::BlankId> {
+		self.0.get_blank_id(id)
+	}
+}
+
+impl<V: LiteralVocabulary> LiteralVocabulary for SharedVocabulary<V> {
+	type Literal = V::Literal;
+
+	fn literal<'l>(&'l self, id: &'l Self::Literal) -> Option<LiteralRef<'l, Self::Iri>> {
+		self.0.literal(id)
+	}
+
+	fn owned_literal(&self, id: Self::Literal) -> Result<Literal<Self::Iri>, Self::Literal> {
+		self.0.owned_literal(id)
+	}
+
+	fn get_literal(&self, id: LiteralRef<Self::Iri>) -> Option<Self::Literal> {
+		self.0.get_literal(id)
+	}
+}
+
+impl<V: LanguageTagVocabulary> LanguageTagVocabulary for SharedVocabulary<V> {
+	type LanguageTag = V::LanguageTag;
+
+	fn language_tag<'l>(&'l self, id: &'l Self::LanguageTag) -> Option<&'l LangTag> {
+		self.0.language_tag(id)
+	}
+
+	fn owned_language_tag(&self, id: Self::LanguageTag) -> Result<LangTagBuf, Self::LanguageTag> {
+		self.0.owned_language_tag(id)
+	}
+
+	fn get_language_tag(&self, tag: &LangTag) -> Option<Self::LanguageTag> {
+		self.0.get_language_tag(tag)
+	}
+}
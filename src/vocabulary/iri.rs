@@ -1,4 +1,4 @@
-use iref::{Iri, IriBuf};
+use iref::{Iri, IriBuf, IriRef, IriRefBuf};
 
 use super::{EmbedIntoVocabulary, EmbeddedIntoVocabulary};
 
@@ -16,6 +16,42 @@ pub trait IriVocabulary {
 
 	/// Returns the id of the given IRI, if any.
 	fn get(&self, iri: &Iri) -> Option<Self::Iri>;
+
+	/// Returns an iterator over the IRIs associated to the given IRI ids.
+	fn resolve_iris<'a>(&'a self, ids: &'a [Self::Iri]) -> ResolveIris<'a, Self> {
+		ResolveIris {
+			vocabulary: self,
+			ids: ids.iter(),
+		}
+	}
+}
+
+/// Iterator over the IRIs associated to a slice of IRI ids.
+///
+/// Returned by [`IriVocabulary::resolve_iris`].
+pub struct ResolveIris<'a, V: ?Sized + IriVocabulary> {
+	vocabulary: &'a V,
+	ids: std::slice::Iter<'a, V::Iri>,
+}
+
+impl<'a, V: ?Sized + IriVocabulary> Iterator for ResolveIris<'a, V> {
+	type Item = Option<&'a Iri>;
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		self.ids.size_hint()
+	}
+
+	fn next(&mut self) -> Option<Self::Item> {
+		self.ids.next().map(|id| self.vocabulary.iri(id))
+	}
+}
+
+impl<'a, V: ?Sized + IriVocabulary> ExactSizeIterator for ResolveIris<'a, V> {}
+
+impl<'a, V: ?Sized + IriVocabulary> DoubleEndedIterator for ResolveIris<'a, V> {
+	fn next_back(&mut self) -> Option<Self::Item> {
+		self.ids.next_back().map(|id| self.vocabulary.iri(id))
+	}
 }
 
 impl<'a, V: IriVocabulary> IriVocabulary for &'a V {
@@ -58,11 +94,33 @@ pub trait IriVocabularyMut: IriVocabulary {
 	/// and the current one is returned.
 	fn insert(&mut self, iri: &Iri) -> Self::Iri;
 
+	/// Inserts an owned IRI to the vocabulary and returns its id.
+	///
+	/// Vocabularies storing IRIs should override this method to move the
+	/// given buffer into their storage instead of copying it.
 	fn insert_owned(&mut self, iri: IriBuf) -> Self::Iri {
 		self.insert(iri.as_iri())
 	}
+
+	/// Inserts an absolute IRI to the vocabulary and returns its id.
+	///
+	/// Fails without inserting anything if the given IRI reference is
+	/// relative, which can be used to catch IRI resolution bugs early since
+	/// RDF terms must be absolute IRIs.
+	fn insert_absolute(&mut self, iri: &IriRef) -> Result<Self::Iri, RelativeIriError> {
+		match Iri::new(iri.as_str()) {
+			Ok(iri) => Ok(self.insert(iri)),
+			Err(_) => Err(RelativeIriError(iri.to_owned())),
+		}
+	}
 }
 
+/// Error returned by [`IriVocabularyMut::insert_absolute`] when the IRI
+/// reference is relative.
+#[derive(Debug, thiserror::Error)]
+#[error("relative IRI reference `{0}`")]
+pub struct RelativeIriError(pub IriRefBuf);
+
 impl<'a, V: IriVocabularyMut> IriVocabularyMut for &'a mut V {
 	fn insert(&mut self, iri: &Iri) -> Self::Iri {
 		V::insert(*self, iri)
@@ -71,6 +129,10 @@ impl<'a, V: IriVocabularyMut> IriVocabularyMut for &'a mut V {
 	fn insert_owned(&mut self, iri: IriBuf) -> Self::Iri {
 		V::insert_owned(*self, iri)
 	}
+
+	fn insert_absolute(&mut self, iri: &IriRef) -> Result<Self::Iri, RelativeIriError> {
+		V::insert_absolute(*self, iri)
+	}
 }
 
 impl<'a, V: IriVocabularyMut> EmbedIntoVocabulary<V> for &'a Iri {
@@ -0,0 +1,108 @@
+use langtag::{LangTag, LangTagBuf};
+
+use super::{EmbedIntoVocabulary, EmbeddedIntoVocabulary};
+
+/// Language tag vocabulary.
+pub trait LanguageTagVocabulary {
+	/// Language tag type.
+	type LanguageTag;
+
+	/// Returns the language tag associated to the given language tag id.
+	fn language_tag<'l>(&'l self, id: &'l Self::LanguageTag) -> Option<&'l LangTag>;
+
+	/// Returns a copy of the language tag associated to the given language tag
+	/// id.
+	fn owned_language_tag(&self, id: Self::LanguageTag) -> Result<LangTagBuf, Self::LanguageTag> {
+		self.language_tag(&id).map(LangTag::to_owned).ok_or(id)
+	}
+
+	/// Returns the id of the given language tag, if any.
+	fn get_language_tag(&self, tag: &LangTag) -> Option<Self::LanguageTag>;
+}
+
+impl<'a, V: LanguageTagVocabulary> LanguageTagVocabulary for &'a V {
+	type LanguageTag = V::LanguageTag;
+
+	fn language_tag<'l>(&'l self, id: &'l Self::LanguageTag) -> Option<&'l LangTag> {
+		V::language_tag(*self, id)
+	}
+
+	fn owned_language_tag(&self, id: Self::LanguageTag) -> Result<LangTagBuf, Self::LanguageTag> {
+		V::owned_language_tag(*self, id)
+	}
+
+	fn get_language_tag(&self, tag: &LangTag) -> Option<Self::LanguageTag> {
+		V::get_language_tag(*self, tag)
+	}
+}
+
+impl<'a, V: LanguageTagVocabulary> LanguageTagVocabulary for &'a mut V {
+	type LanguageTag = V::LanguageTag;
+
+	fn language_tag<'l>(&'l self, id: &'l Self::LanguageTag) -> Option<&'l LangTag> {
+		V::language_tag(*self, id)
+	}
+
+	fn owned_language_tag(&self, id: Self::LanguageTag) -> Result<LangTagBuf, Self::LanguageTag> {
+		V::owned_language_tag(*self, id)
+	}
+
+	fn get_language_tag(&self, tag: &LangTag) -> Option<Self::LanguageTag> {
+		V::get_language_tag(*self, tag)
+	}
+}
+
+/// Mutable language tag vocabulary.
+pub trait LanguageTagVocabularyMut: LanguageTagVocabulary {
+	/// Inserts a language tag to the vocabulary and returns its id.
+	///
+	/// If the language tag was already present in the vocabulary, no new id
+	/// is created and the current one is returned.
+	fn insert_language_tag(&mut self, tag: &LangTag) -> Self::LanguageTag;
+
+	fn insert_owned_language_tag(&mut self, tag: LangTagBuf) -> Self::LanguageTag {
+		self.insert_language_tag(tag.as_lang_tag())
+	}
+}
+
+impl<'a, V: LanguageTagVocabularyMut> LanguageTagVocabularyMut for &'a mut V {
+	fn insert_language_tag(&mut self, tag: &LangTag) -> Self::LanguageTag {
+		V::insert_language_tag(*self, tag)
+	}
+
+	fn insert_owned_language_tag(&mut self, tag: LangTagBuf) -> Self::LanguageTag {
+		V::insert_owned_language_tag(*self, tag)
+	}
+}
+
+impl<'a, V: LanguageTagVocabularyMut> EmbedIntoVocabulary<V> for &'a LangTag {
+	type Embedded = V::LanguageTag;
+
+	fn embed_into_vocabulary(self, vocabulary: &mut V) -> Self::Embedded {
+		vocabulary.insert_language_tag(self)
+	}
+}
+
+impl<V: LanguageTagVocabularyMut> EmbedIntoVocabulary<V> for LangTagBuf {
+	type Embedded = V::LanguageTag;
+
+	fn embed_into_vocabulary(self, vocabulary: &mut V) -> Self::Embedded {
+		vocabulary.insert_owned_language_tag(self)
+	}
+}
+
+impl<'a, V: LanguageTagVocabularyMut> EmbeddedIntoVocabulary<V> for &'a LangTag {
+	type Embedded = V::LanguageTag;
+
+	fn embedded_into_vocabulary(&self, vocabulary: &mut V) -> Self::Embedded {
+		vocabulary.insert_language_tag(self)
+	}
+}
+
+impl<V: LanguageTagVocabularyMut> EmbeddedIntoVocabulary<V> for LangTagBuf {
+	type Embedded = V::LanguageTag;
+
+	fn embedded_into_vocabulary(&self, vocabulary: &mut V) -> Self::Embedded {
+		vocabulary.insert_language_tag(self.as_lang_tag())
+	}
+}
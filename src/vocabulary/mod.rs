@@ -7,13 +7,17 @@
 //!
 //! Using vocabularies, an IRI can be represented as a simple integer, or enum
 //! type, drastically reducing the cost of storage and comparison.
+mod batch;
 mod blank_id;
 mod iri;
+mod language_tag;
 mod literal;
 
+pub use batch::*;
 pub use blank_id::*;
 use iref::IriBuf;
 pub use iri::*;
+pub use language_tag::*;
 pub use literal::*;
 
 mod r#impl;
@@ -256,3 +260,83 @@ impl<V, T: TryExtractFromVocabulary<V>> TryExtractFromVocabulary<V> for Option<T
 			.transpose()
 	}
 }
+
+/// Result of [`try_extract_all`].
+///
+/// On error, lists every failure along with the position of the failing
+/// value.
+pub type TryExtractAllResult<V, T> = Result<
+	Vec<<T as TryExtractFromVocabulary<V>>::Extracted>,
+	Vec<(usize, <T as TryExtractFromVocabulary<V>>::Error)>,
+>;
+
+/// Extracts every given value from the vocabulary, collecting all the
+/// failures.
+///
+/// Contrarily to collecting the results of
+/// [`TryExtractFromVocabulary::try_extract_from_vocabulary`] into a
+/// `Result<Vec<_>, _>`, this does not stop at the first failure: on error,
+/// every failure is returned along with the position of the failing value.
+///
+/// This works with any extractable value, such as a batch of quads.
+pub fn try_extract_all<V, T: TryExtractFromVocabulary<V>>(
+	values: impl IntoIterator<Item = T>,
+	vocabulary: &V,
+) -> TryExtractAllResult<V, T> {
+	let mut extracted = Vec::new();
+	let mut errors = Vec::new();
+
+	for (i, value) in values.into_iter().enumerate() {
+		match value.try_extract_from_vocabulary(vocabulary) {
+			Ok(value) => {
+				if errors.is_empty() {
+					extracted.push(value)
+				}
+			}
+			Err(e) => errors.push((i, e)),
+		}
+	}
+
+	if errors.is_empty() {
+		Ok(extracted)
+	} else {
+		Err(errors)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{Quad, QuadExportFailed};
+
+	/// Value that can only be extracted when even.
+	struct Even(u32);
+
+	impl TryExtractFromVocabulary<()> for Even {
+		type Extracted = u32;
+		type Error = u32;
+
+		fn try_extract_from_vocabulary(self, _vocabulary: &()) -> Result<u32, u32> {
+			if self.0 % 2 == 0 {
+				Ok(self.0)
+			} else {
+				Err(self.0)
+			}
+		}
+	}
+
+	#[test]
+	fn extract_all() {
+		let quad = |s, p, o| Quad(Even(s), Even(p), Even(o), None::<Even>);
+
+		let extracted = try_extract_all([quad(0, 2, 4), quad(6, 8, 10)], &()).unwrap();
+		let expected: [Quad<u32>; 2] = [Quad(0, 2, 4, None), Quad(6, 8, 10, None)];
+		assert_eq!(extracted, expected);
+
+		let errors =
+			try_extract_all([quad(1, 2, 4), quad(0, 2, 4), quad(0, 2, 3)], &()).unwrap_err();
+		assert_eq!(errors.len(), 2);
+		assert!(matches!(errors[0], (0, QuadExportFailed::Subject(1))));
+		assert!(matches!(errors[1], (2, QuadExportFailed::Object(3))))
+	}
+}
@@ -0,0 +1,79 @@
+//! Streaming N-Triples and N-Quads serialization.
+use std::io;
+
+use crate::RdfDisplay;
+
+/// Writes the given triples as an N-Triples document.
+///
+/// Each triple is written on its own line, terminated by ` .\n`, as soon as
+/// it is yielded by the iterator so that the whole document is never held in
+/// memory. The writer is not buffered: consider wrapping it in an
+/// [`io::BufWriter`].
+///
+/// ```
+/// use rdf_types::{LexicalTriple, write::write_ntriples};
+///
+/// let triple: LexicalTriple = "_:a <http://example.org/#p> \"v\" .".parse().unwrap();
+///
+/// let mut output = Vec::new();
+/// write_ntriples(&mut output, [&triple]).unwrap();
+/// assert_eq!(output, b"_:a <http://example.org/#p> \"v\" .\n");
+/// ```
+pub fn write_ntriples<W: io::Write, T: RdfDisplay>(
+	writer: W,
+	triples: impl IntoIterator<Item = T>,
+) -> io::Result<()> {
+	write_statements(writer, triples)
+}
+
+/// Writes the given quads as an N-Quads document.
+///
+/// Each quad is written on its own line, terminated by ` .\n`, as soon as it
+/// is yielded by the iterator so that the whole document is never held in
+/// memory. The writer is not buffered: consider wrapping it in an
+/// [`io::BufWriter`].
+pub fn write_nquads<W: io::Write, Q: RdfDisplay>(
+	writer: W,
+	quads: impl IntoIterator<Item = Q>,
+) -> io::Result<()> {
+	write_statements(writer, quads)
+}
+
+fn write_statements<W: io::Write, T: RdfDisplay>(
+	mut writer: W,
+	statements: impl IntoIterator<Item = T>,
+) -> io::Result<()> {
+	for statement in statements {
+		writeln!(writer, "{} .", statement.rdf_display())?;
+	}
+
+	writer.flush()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{LexicalQuad, LexicalTriple};
+
+	#[test]
+	fn ntriples_round_trip() {
+		let document = "_:a <http://example.org/#p> \"line\\nbreak \\\"quoted\\\"\" .\n\
+			<http://example.org/#s> <http://example.org/#p> \"1\"^^<http://www.w3.org/2001/XMLSchema#integer> .\n";
+		let triples: Vec<LexicalTriple> = document.lines().map(|l| l.parse().unwrap()).collect();
+
+		let mut output = Vec::new();
+		write_ntriples(&mut output, &triples).unwrap();
+		assert_eq!(String::from_utf8(output).unwrap(), document)
+	}
+
+	#[test]
+	fn nquads_round_trip() {
+		let document = "_:a <http://example.org/#p> _:b <http://example.org/#g> .\n\
+			_:b <http://example.org/#p> \"v\"@en .\n";
+		let quads: Vec<LexicalQuad> = document.lines().map(|l| l.parse().unwrap()).collect();
+
+		let mut output = Vec::new();
+		write_nquads(&mut output, quads.iter()).unwrap();
+		assert_eq!(String::from_utf8(output).unwrap(), document)
+	}
+}
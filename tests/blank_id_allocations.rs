@@ -0,0 +1,40 @@
+//! Counts the heap allocations performed when minting blank node
+//! identifiers.
+use rdf_types::generator;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+struct CountingAllocator;
+
+thread_local! {
+	/// Number of allocations performed by the current thread, so that
+	/// concurrently running tests do not interfere.
+	static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+	unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+		let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+		System.alloc(layout)
+	}
+
+	unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+		System.dealloc(ptr, layout)
+	}
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+#[test]
+fn mint_blank_ids_without_allocating() {
+	let mut generator = generator::Blank::new_with_prefix("b".to_owned());
+
+	let before = ALLOCATIONS.with(Cell::get);
+	for _ in 0..100_000 {
+		std::hint::black_box(generator.next_blank_id());
+	}
+	let allocations = ALLOCATIONS.with(Cell::get) - before;
+
+	assert_eq!(allocations, 0)
+}